@@ -181,6 +181,36 @@ pub fn fast_polynomial_evaluate(
         .collect()
 }
 
+// Evaluate a polynomial at an arbitrary, small set of points via Horner's method, one
+// point at a time: O(n * points.len()) rather than the O(n*log(n)) of `fast_polynomial_evaluate`.
+// Prefer this over the NTT-based path when `points` isn't a root-of-unity domain, or
+// when there are only a handful of points to evaluate at -- building the field and
+// running an NTT over the whole coefficient vector is overkill for that case.
+pub fn fast_polynomial_evaluate_points(
+    pol_coefficients: &[i128],
+    modulus: i128,
+    points: &[i128],
+) -> Vec<i128> {
+    let field = PrimeField::new(modulus);
+    let coefficients: Vec<PrimeFieldElement> = pol_coefficients
+        .iter()
+        .map(|&x| PrimeFieldElement::new(x, &field))
+        .collect();
+    points
+        .iter()
+        .map(|&x| {
+            let x_pfe = PrimeFieldElement::new(x, &field);
+            coefficients
+                .iter()
+                .rev()
+                .fold(PrimeFieldElement::new(0, &field), |acc, &c| {
+                    acc * x_pfe + c
+                })
+                .value
+        })
+        .collect()
+}
+
 // FFT has a runtime of O(N*log(N)) whereas the DFT
 // algorithm has a runtime of O(N^2).
 
@@ -464,6 +494,21 @@ mod test_vectors {
         assert_eq!(fast_values, input_y_values);
     }
 
+    #[test]
+    fn fast_polynomial_evaluate_points_matches_ntt_on_root_of_unity_domain_test() {
+        let field = PrimeField::new(337i128);
+        let primitive_eighth_root = 85i128;
+        let coefficients = vec![46i128, 169, 29, 149, 126, 262, 140, 93];
+
+        let ntt_values = fast_polynomial_evaluate(&coefficients, field.q, primitive_eighth_root);
+
+        let omega = PrimeFieldElement::new(primitive_eighth_root, &field);
+        let points: Vec<i128> = (0..8).map(|x| omega.mod_pow(x).value).collect();
+        let horner_values = fast_polynomial_evaluate_points(&coefficients, field.q, &points);
+
+        assert_eq!(ntt_values, horner_values);
+    }
+
     // test vectors found here:
     // https://math.stackexchange.com/questions/1437624/number-theoretic-transform-ntt-example-not-working-out
     #[test]
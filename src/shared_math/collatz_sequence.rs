@@ -10,6 +10,47 @@ use crate::utils;
 use serde::Serialize;
 use std::error::Error;
 
+// Iterates the plain Collatz sequence (n -> n/2 for even n, n -> 3n+1 for odd n),
+// yielding `start` itself first and terminating after yielding 1.
+pub struct CollatzIterator {
+    current: Option<u64>,
+}
+
+impl CollatzIterator {
+    pub fn new(start: u64) -> Self {
+        Self {
+            current: Some(start),
+        }
+    }
+}
+
+impl Iterator for CollatzIterator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let value = self.current?;
+        self.current = if value == 1 {
+            None
+        } else if value % 2 == 0 {
+            Some(value / 2)
+        } else {
+            Some(3 * value + 1)
+        };
+
+        Some(value)
+    }
+}
+
+// The number of steps it takes for the Collatz sequence starting at `n` to reach 1.
+pub fn stopping_time(n: u64) -> usize {
+    CollatzIterator::new(n).count() - 1
+}
+
+// The largest value reached by the Collatz sequence starting at `n`.
+pub fn max_value_reached(n: u64) -> u64 {
+    CollatzIterator::new(n).max().unwrap()
+}
+
 #[derive(Clone, Debug, Serialize, PartialEq)]
 pub struct CollatzStarkProof {
     bq_merkle_root: [u8; 32],
@@ -820,4 +861,25 @@ mod collatz_sequence_test {
         }
         assert_eq!(stark_proof, stark_proof_deserialized);
     }
+
+    #[test]
+    fn collatz_iterator_test() {
+        let sequence: Vec<u64> = CollatzIterator::new(6).collect();
+        assert_eq!(vec![6, 3, 10, 5, 16, 8, 4, 2, 1], sequence);
+
+        let sequence_from_one: Vec<u64> = CollatzIterator::new(1).collect();
+        assert_eq!(vec![1], sequence_from_one);
+    }
+
+    #[test]
+    fn stopping_time_test() {
+        assert_eq!(8, stopping_time(6));
+        assert_eq!(0, stopping_time(1));
+    }
+
+    #[test]
+    fn max_value_reached_test() {
+        assert_eq!(16, max_value_reached(6));
+        assert_eq!(1, max_value_reached(1));
+    }
 }
@@ -40,6 +40,35 @@ impl<T: num_traits::Float + Clone + Copy> ComplexNumber<T> {
             i: imaginary.sin(),
         }
     }
+
+    // Returns (radius, angle), with angle in radians.
+    pub fn to_polar(&self) -> (T, T) {
+        let radius = (self.r * self.r + self.i * self.i).sqrt();
+        let angle = self.i.atan2(self.r);
+        (radius, angle)
+    }
+
+    pub fn from_polar(radius: T, angle: T) -> Self {
+        Self {
+            r: radius * angle.cos(),
+            i: radius * angle.sin(),
+        }
+    }
+
+    // The n distinct complex n-th roots of `self`, found by taking the n-th root of
+    // the radius and spacing the angles evenly by 2*pi/n around the polar form.
+    pub fn nth_roots(&self, n: usize) -> Vec<Self> {
+        let (radius, angle) = self.to_polar();
+        let n_as_t = T::from(n).unwrap();
+        let root_radius = radius.powf(T::one() / n_as_t);
+        let two_pi = T::from(2).unwrap() * T::from(std::f64::consts::PI).unwrap();
+        (0..n)
+            .map(|k| {
+                let root_angle = (angle + T::from(k).unwrap() * two_pi) / n_as_t;
+                Self::from_polar(root_radius, root_angle)
+            })
+            .collect()
+    }
 }
 
 impl<T: num_traits::Num + Clone + Copy + Display> std::fmt::Display for ComplexNumber<T> {
@@ -202,4 +231,37 @@ mod test_complex_numbers {
         assert!((res.r + 21.0f64 / 26.0f64).abs() < 0.0001);
         assert!((res.i - 6.0f64 / 13.0f64).abs() < 0.0001);
     }
+
+    #[test]
+    fn to_polar_and_from_polar_round_trip_test() {
+        use super::*;
+
+        let z = ComplexNumber::new(3.0f64, 4.0f64);
+        let (radius, angle) = z.to_polar();
+        assert!((radius - 5.0f64).abs() < 0.0001);
+
+        let roundtripped = ComplexNumber::from_polar(radius, angle);
+        assert!((roundtripped.r - z.r).abs() < 0.0001);
+        assert!((roundtripped.i - z.i).abs() < 0.0001);
+    }
+
+    #[test]
+    fn nth_roots_of_unity_test() {
+        use super::*;
+
+        let one = ComplexNumber::new(1.0f64, 0.0f64);
+        let roots = one.nth_roots(4);
+        assert_eq!(4, roots.len());
+
+        let expected = vec![
+            ComplexNumber::new(1.0f64, 0.0f64),
+            ComplexNumber::new(0.0f64, 1.0f64),
+            ComplexNumber::new(-1.0f64, 0.0f64),
+            ComplexNumber::new(0.0f64, -1.0f64),
+        ];
+        for (root, expected_root) in roots.iter().zip(expected.iter()) {
+            assert!((root.r - expected_root.r).abs() < 0.0001);
+            assert!((root.i - expected_root.i).abs() < 0.0001);
+        }
+    }
 }
@@ -0,0 +1,228 @@
+use std::fmt;
+
+use super::polynomial_quotient_ring::PolynomialQuotientRing;
+use super::prime_field_element::{PrimeField, PrimeFieldElement};
+use super::prime_field_polynomial::PrimeFieldPolynomial;
+use super::traits::{FieldElement, IdentityValues, ModPowU64, New};
+
+/// A degree-`n` extension of a `PrimeField`: `F_q[x]/(f(x))` for an irreducible
+/// polynomial `f` of degree `n`. Element arithmetic is delegated to
+/// `PrimeFieldPolynomial`, reusing its reduction and its extended-Euclidean-algorithm
+/// inversion, via an internal `PolynomialQuotientRing`, which is what lets `FieldElement`
+/// be implemented here. Neither the FRI prover functions in `low_degree_test.rs` nor the
+/// NTT functions in `ntt.rs` accept `ExtensionFieldElement`: they take
+/// `PrimeFieldElement`-based or `std::ops`-based values, which this type does not provide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionField {
+    pqr: PolynomialQuotientRing,
+}
+
+impl ExtensionField {
+    /// `base` supplies the coefficient field's prime; `modulus_poly` is the
+    /// degree-`n` irreducible polynomial defining the extension, lowest-degree
+    /// coefficient first (`PrimeFieldPolynomial`'s convention). The caller is
+    /// responsible for `modulus_poly` actually being irreducible over `base` -- this
+    /// is not checked, exactly like `PolynomialQuotientRing::new` doesn't check that
+    /// `x^n+1` is irreducible either.
+    pub fn new(base: &PrimeField, modulus_poly: Vec<i128>) -> Self {
+        ExtensionField {
+            pqr: PolynomialQuotientRing::new_with_modulus(base.q, modulus_poly),
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.pqr.n as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionFieldElement<'a> {
+    pub coefficients: Vec<i128>,
+    pub field: &'a ExtensionField,
+}
+
+impl<'a> ExtensionFieldElement<'a> {
+    pub fn new(field: &'a ExtensionField, coefficients: Vec<i128>) -> Self {
+        ExtensionFieldElement {
+            coefficients,
+            field,
+        }
+        .reduced()
+    }
+
+    fn as_poly(&self) -> PrimeFieldPolynomial<'a> {
+        PrimeFieldPolynomial {
+            coefficients: self.coefficients.clone(),
+            pqr: &self.field.pqr,
+        }
+    }
+
+    fn from_poly(field: &'a ExtensionField, poly: PrimeFieldPolynomial<'a>) -> Self {
+        ExtensionFieldElement {
+            coefficients: poly.coefficients,
+            field,
+        }
+    }
+
+    fn reduced(self) -> Self {
+        Self::from_poly(self.field, self.as_poly().modulus())
+    }
+
+    /// The Frobenius endomorphism `x -> x^q`, where `q` is the base field's prime.
+    /// Fixes exactly the base field's elements embedded in the extension; composing
+    /// it `degree()` times with itself is the identity.
+    pub fn frobenius(&self) -> Self {
+        self.mod_pow(self.field.pqr.q)
+    }
+}
+
+impl<'a> ModPowU64 for ExtensionFieldElement<'a> {
+    fn mod_pow_u64(&self, pow: u64) -> Self {
+        self.mod_pow(pow as i128)
+    }
+}
+
+impl<'a> IdentityValues for ExtensionFieldElement<'a> {
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    fn is_one(&self) -> bool {
+        self.coefficients == [1]
+    }
+
+    fn ring_zero(&self) -> Self {
+        ExtensionFieldElement {
+            coefficients: vec![],
+            field: self.field,
+        }
+    }
+
+    fn ring_one(&self) -> Self {
+        ExtensionFieldElement {
+            coefficients: vec![1],
+            field: self.field,
+        }
+    }
+}
+
+impl<'a> New for ExtensionFieldElement<'a> {
+    fn new_from_usize(&self, value: usize) -> Self {
+        Self::new(self.field, vec![value as i128])
+    }
+}
+
+impl<'a> FieldElement for ExtensionFieldElement<'a> {
+    type Exponent = i128;
+
+    fn zero(&self) -> Self {
+        self.ring_zero()
+    }
+
+    fn one(&self) -> Self {
+        self.ring_one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::from_poly(self.field, self.as_poly().add(&other.as_poly()))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self::from_poly(self.field, self.as_poly().mul(&other.as_poly()).modulus())
+    }
+
+    fn neg(&self) -> Self {
+        Self::from_poly(self.field, self.as_poly().scalar_mul(-1))
+    }
+
+    fn inverse(&self) -> Self {
+        Self::from_poly(
+            self.field,
+            self.as_poly()
+                .inverse()
+                .expect("Cannot invert the zero element of an extension field"),
+        )
+    }
+
+    fn mod_pow(&self, exponent: Self::Exponent) -> Self {
+        // Exponentiation by repeated squaring, same approach as
+        // `PrimeFieldElement::mod_pow`.
+        let mut acc = self.one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = acc.mul(&base);
+            }
+            base = base.mul(&base);
+            exponent >>= 1;
+        }
+        acc
+    }
+
+    fn from_bytes_raw(&self, buf: &[u8]) -> Self {
+        let degree = self.field.degree();
+        let chunk_size = (buf.len() / degree).max(1);
+        let coefficients: Vec<i128> = buf
+            .chunks(chunk_size)
+            .take(degree)
+            .map(|chunk| PrimeFieldElement::from_bytes_raw(&self.field.pqr.q, chunk))
+            .collect();
+        Self::new(self.field, coefficients)
+    }
+}
+
+impl<'a> fmt::Display for ExtensionFieldElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_poly())
+    }
+}
+
+#[cfg(test)]
+mod extension_field_tests {
+    use super::*;
+
+    #[test]
+    fn degree_2_extension_frobenius_and_order_test() {
+        // GF(7)[x]/(x^2+1) is GF(49): x^2+1 has no root mod 7 (checked by hand:
+        // x^2 mod 7 is never 6 for x in 0..7), so it's irreducible there.
+        let base = PrimeField::new(7);
+        let extension = ExtensionField::new(&base, vec![1, 0, 1]); // x^2 + 1
+
+        let alpha = ExtensionFieldElement::new(&extension, vec![0, 1]); // the element "x"
+        let one = alpha.one();
+
+        // Frobenius is an automorphism of the extension that fixes the base field:
+        // applying it `degree()` times returns the original element.
+        let mut frobenius_orbit = alpha.clone();
+        for _ in 0..extension.degree() {
+            frobenius_orbit = frobenius_orbit.frobenius();
+        }
+        assert_eq!(alpha, frobenius_orbit);
+
+        // Frobenius fixes base-field elements embedded as constant polynomials.
+        let base_element = ExtensionFieldElement::new(&extension, vec![3]);
+        assert_eq!(base_element, base_element.frobenius());
+
+        // The multiplicative group of GF(49) has order 48, so every nonzero
+        // element's order divides 48, and `alpha^48 == 1`.
+        assert_eq!(one, alpha.mod_pow(48));
+    }
+
+    #[test]
+    fn extension_field_arithmetic_round_trips_test() {
+        let base = PrimeField::new(5);
+        let extension = ExtensionField::new(&base, vec![2, 0, 1]); // x^2 + 2
+
+        let a = ExtensionFieldElement::new(&extension, vec![1, 2]);
+        let b = ExtensionFieldElement::new(&extension, vec![3, 4]);
+
+        let sum = a.add(&b);
+        let difference = sum.add(&b.neg());
+        assert_eq!(a, difference);
+
+        let product = a.mul(&b);
+        let quotient = product.mul(&b.inverse());
+        assert_eq!(a, quotient);
+    }
+}
@@ -1,5 +1,6 @@
 use num_traits::Num;
 use num_traits::One;
+use num_traits::ToPrimitive;
 use num_traits::Zero;
 use std::convert::From;
 use std::fmt::{Debug, Display};
@@ -51,7 +52,7 @@ impl<U: num_traits::Num + Clone + Copy + Debug> Num for Fraction<U> {
 }
 
 impl<T: num_traits::Num + Clone + Copy + Debug> Fraction<T> {
-    pub fn reduce(mut dividend: T, mut divisor: T) -> Self {
+    fn reduce_fraction(mut dividend: T, mut divisor: T) -> Self {
         let (reducer, ..) = PrimeFieldElement::eea(dividend, divisor);
         if reducer != num_traits::one() {
             dividend = dividend / reducer;
@@ -63,8 +64,42 @@ impl<T: num_traits::Num + Clone + Copy + Debug> Fraction<T> {
         Self { dividend, divisor }
     }
 
+    // Divide dividend and divisor by their GCD. Uses the same sign convention as
+    // `reduce_fraction`/`new` (minus sign on the divisor), so this is idempotent
+    // with values already produced by `new`.
+    pub fn reduce(&self) -> Self {
+        Self::reduce_fraction(self.dividend, self.divisor)
+    }
+
     pub fn new(dividend: T, divisor: T) -> Self {
-        Self::reduce(dividend, divisor)
+        Self::reduce_fraction(dividend, divisor)
+    }
+
+    pub fn to_f64(&self) -> f64
+    where
+        T: ToPrimitive,
+    {
+        self.dividend.to_f64().unwrap() / self.divisor.to_f64().unwrap()
+    }
+
+    // Standard continued-fraction expansion: repeatedly take the integer part and
+    // recurse on the reciprocal of the remainder, stopping once the remainder is zero.
+    pub fn continued_fraction(&self) -> Vec<i64>
+    where
+        T: ToPrimitive,
+    {
+        let mut dividend = self.dividend;
+        let mut divisor = self.divisor;
+        let mut terms = vec![];
+        while divisor != num_traits::zero() {
+            let quotient = dividend / divisor;
+            let remainder = dividend % divisor;
+            terms.push(quotient.to_i64().unwrap());
+            dividend = divisor;
+            divisor = remainder;
+        }
+
+        terms
     }
 
     pub fn get_dividend(&self) -> T {
@@ -94,11 +129,11 @@ impl<T: num_traits::Num + Clone + Copy + Debug> Fraction<T> {
     }
 
     pub fn scalar_mul(&self, scalar: T) -> Self {
-        Self::reduce(scalar * self.dividend, self.divisor)
+        Self::reduce_fraction(scalar * self.dividend, self.divisor)
     }
 
     pub fn scalar_div(&self, scalar: T) -> Self {
-        Self::reduce(self.dividend, scalar * self.divisor)
+        Self::reduce_fraction(self.dividend, scalar * self.divisor)
     }
 }
 
@@ -122,7 +157,7 @@ impl<U: num_traits::Num + Clone + Copy + Debug> Div for Fraction<U> {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        Self::reduce(self.dividend * other.divisor, self.divisor * other.dividend)
+        Self::reduce_fraction(self.dividend * other.divisor, self.divisor * other.dividend)
     }
 }
 
@@ -132,7 +167,7 @@ impl<U: num_traits::Num + Clone + Copy + Debug> Add for Fraction<U> {
     fn add(self, other: Self) -> Self {
         let common_divisor = self.divisor * other.divisor;
         let dividend = self.dividend * other.divisor + other.dividend * self.divisor;
-        Self::reduce(dividend, common_divisor)
+        Self::reduce_fraction(dividend, common_divisor)
     }
 }
 
@@ -142,7 +177,7 @@ impl<U: num_traits::Num + Clone + Copy + Debug> Sub for Fraction<U> {
     fn sub(self, other: Self) -> Self {
         let common_divisor = self.divisor * other.divisor;
         let dividend = self.dividend * other.divisor - other.dividend * self.divisor;
-        Self::reduce(dividend, common_divisor)
+        Self::reduce_fraction(dividend, common_divisor)
     }
 }
 
@@ -150,7 +185,7 @@ impl<U: num_traits::Num + Clone + Copy + Debug> Mul for Fraction<U> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        Self::reduce(self.dividend * other.dividend, self.divisor * other.divisor)
+        Self::reduce_fraction(self.dividend * other.dividend, self.divisor * other.divisor)
     }
 }
 
@@ -235,4 +270,35 @@ mod test_fractions {
         // Ensure that negative sign is always consistent and unique
         assert_eq!(Fraction::new(1, -2), Fraction::new(-1, 2));
     }
+
+    #[test]
+    fn reduce_test() {
+        use super::*;
+
+        assert_eq!(Fraction::new(6, 8).reduce(), Fraction::new(3, 4));
+        assert_eq!(Fraction::new(-1, -2).reduce(), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(1, -2).reduce(), Fraction::new(-1, 2));
+        assert_eq!(Fraction::new(0, 5).reduce(), Fraction::new(0, 1));
+
+        // `reduce` is idempotent: values are already kept in lowest terms by `new`.
+        let already_reduced = Fraction::new(3, 4);
+        assert_eq!(already_reduced.reduce(), already_reduced);
+    }
+
+    #[test]
+    fn to_f64_test() {
+        use super::*;
+
+        assert_eq!(0.25, Fraction::new(1, 4).to_f64());
+        assert_eq!(-0.5, Fraction::new(-1, 2).to_f64());
+    }
+
+    #[test]
+    fn continued_fraction_test() {
+        use super::*;
+
+        assert_eq!(vec![3, 7], Fraction::new(22, 7).continued_fraction());
+        assert_eq!(vec![7], Fraction::new(7, 1).continued_fraction());
+        assert_eq!(vec![0, 4], Fraction::new(1, 4).continued_fraction());
+    }
 }
@@ -0,0 +1,280 @@
+use crate::shared_math::traits::{FieldElement, IdentityValues, ModPowU64, New};
+use serde::Serialize;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The Goldilocks prime: `2^64 - 2^32 + 1`. Its multiplicative group has order
+/// `2^32 * (2^32 - 1)`, giving it elements of every power-of-two order up to `2^32`,
+/// which is what makes it a popular modulus for FRI-based STARKs; see
+/// `low_degree_test`'s `prover_u64`/`verify_u64`.
+pub const GOLDILOCKS_PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Reduce a 128-bit value mod `GOLDILOCKS_PRIME` without a general integer division,
+/// using the identity `2^64 ≡ 2^32 - 1 (mod GOLDILOCKS_PRIME)`: splitting `x` into a
+/// high and low 64-bit half and folding the high half in by that factor shrinks `x` by
+/// roughly 32 bits per step, so a few folds bring it under `2^64`, at which point
+/// subtracting the prime (at most once) finishes the job.
+fn reduce_u128(mut x: u128) -> u64 {
+    while x >> 64 != 0 {
+        let hi = (x >> 64) as u64;
+        let lo = x as u64;
+        x = lo as u128 + hi as u128 * (u32::MAX as u128);
+    }
+
+    let mut result = x as u64;
+    while result >= GOLDILOCKS_PRIME {
+        result -= GOLDILOCKS_PRIME;
+    }
+    result
+}
+
+/// A field element modulo the Goldilocks prime. Unlike `PrimeFieldElement`, which
+/// carries a runtime modulus and so must reduce with a general `%`, every operation
+/// here is specialized to `GOLDILOCKS_PRIME`'s shape, replacing division with shifts,
+/// multiplication by `u32::MAX`, and a handful of conditional subtractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Hash)]
+pub struct Goldilocks {
+    pub value: u64,
+}
+
+impl Goldilocks {
+    pub fn new(value: u64) -> Self {
+        Self {
+            value: if value >= GOLDILOCKS_PRIME {
+                value - GOLDILOCKS_PRIME
+            } else {
+                value
+            },
+        }
+    }
+
+    pub fn mod_pow(&self, exponent: u64) -> Self {
+        let mut acc = Self::new(1);
+        let mut base = *self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        acc
+    }
+
+    /// Inverse via Fermat's little theorem: `self^(p - 2) == self^-1 (mod p)` for any
+    /// nonzero `self`, since the multiplicative group has order `p - 1`.
+    pub fn inv(&self) -> Self {
+        self.mod_pow(GOLDILOCKS_PRIME - 2)
+    }
+}
+
+impl IdentityValues for Goldilocks {
+    fn ring_zero(&self) -> Self {
+        Self::new(0)
+    }
+
+    fn ring_one(&self) -> Self {
+        Self::new(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    fn is_one(&self) -> bool {
+        self.value == 1
+    }
+}
+
+impl New for Goldilocks {
+    fn new_from_usize(&self, value: usize) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl ModPowU64 for Goldilocks {
+    fn mod_pow_u64(&self, pow: u64) -> Self {
+        self.mod_pow(pow)
+    }
+}
+
+impl FieldElement for Goldilocks {
+    type Exponent = u64;
+
+    fn zero(&self) -> Self {
+        self.ring_zero()
+    }
+
+    fn one(&self) -> Self {
+        self.ring_one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    fn neg(&self) -> Self {
+        -*self
+    }
+
+    fn inverse(&self) -> Self {
+        self.inv()
+    }
+
+    fn mod_pow(&self, exponent: Self::Exponent) -> Self {
+        Goldilocks::mod_pow(self, exponent)
+    }
+
+    fn from_bytes_raw(&self, buf: &[u8]) -> Self {
+        let mut acc: u128 = 0;
+        for &byte in buf {
+            acc = (acc << 8) | byte as u128;
+        }
+        Self::new(reduce_u128(acc))
+    }
+}
+
+impl fmt::Display for Goldilocks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Add for Goldilocks {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.value as u128 + other.value as u128;
+        Self::new(if sum >= GOLDILOCKS_PRIME as u128 {
+            (sum - GOLDILOCKS_PRIME as u128) as u64
+        } else {
+            sum as u64
+        })
+    }
+}
+
+impl Sub for Goldilocks {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(if self.value >= other.value {
+            self.value - other.value
+        } else {
+            GOLDILOCKS_PRIME - (other.value - self.value)
+        })
+    }
+}
+
+impl Mul for Goldilocks {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(reduce_u128(self.value as u128 * other.value as u128))
+    }
+}
+
+impl Div for Goldilocks {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl Rem for Goldilocks {
+    type Output = Self;
+
+    // Prime fields have no notion of remainder; `Polynomial<T>` only needs the `Rem`
+    // bound to satisfy its generic `divide`, which never calls it for a field element.
+    fn rem(self, _other: Self) -> Self {
+        Self::new(0)
+    }
+}
+
+impl Neg for Goldilocks {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(GOLDILOCKS_PRIME - self.value)
+    }
+}
+
+#[cfg(test)]
+mod test_goldilocks {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_traits::ToPrimitive;
+
+    fn to_bigint(a: Goldilocks) -> BigInt {
+        BigInt::from(a.value)
+    }
+
+    fn modulus() -> BigInt {
+        BigInt::from(GOLDILOCKS_PRIME)
+    }
+
+    #[test]
+    fn multiplication_matches_bigint_reference_test() {
+        let a = Goldilocks::new(18446744069414584320); // p - 1
+        let b = Goldilocks::new(4294967295); // 2^32 - 1
+        let expected = (to_bigint(a) * to_bigint(b)) % modulus();
+        assert_eq!(expected.to_u64().unwrap(), (a * b).value);
+
+        for (x, y) in [(0u64, 0u64), (1, 1), (2, 3), (u64::MAX, u64::MAX), (7, 0)] {
+            let a = Goldilocks::new(x);
+            let b = Goldilocks::new(y);
+            let expected = (to_bigint(a) * to_bigint(b)) % modulus();
+            assert_eq!(expected.to_u64().unwrap(), (a * b).value);
+        }
+    }
+
+    #[test]
+    fn inversion_matches_bigint_reference_test() {
+        for value in [1u64, 2, 3, 7, 4294967295, 18446744069414584320] {
+            let a = Goldilocks::new(value);
+            let inverse = a.inv();
+            assert_eq!(Goldilocks::new(1), a * inverse);
+
+            // Cross-check against a reference extended-Euclidean inverse over BigInt.
+            let (gcd, _, t) = {
+                let (mut old_r, mut r) = (modulus(), to_bigint(a));
+                let (mut old_s, mut s) = (BigInt::from(0), BigInt::from(1));
+                while r != BigInt::from(0) {
+                    let quotient = old_r.clone() / r.clone();
+                    let new_r = old_r - quotient.clone() * r.clone();
+                    old_r = r;
+                    r = new_r;
+                    let new_s = old_s - quotient * s.clone();
+                    old_s = s;
+                    s = new_s;
+                }
+                (old_r, old_s.clone(), old_s)
+            };
+            assert_eq!(BigInt::from(1), gcd);
+            let expected = ((t % modulus()) + modulus()) % modulus();
+            assert_eq!(expected.to_u64().unwrap(), inverse.value);
+        }
+    }
+
+    #[test]
+    fn addition_and_subtraction_wrap_around_modulus_test() {
+        let a = Goldilocks::new(GOLDILOCKS_PRIME - 1);
+        let one = Goldilocks::new(1);
+        assert_eq!(Goldilocks::new(0), a + one);
+        assert_eq!(a, Goldilocks::new(0) - one);
+    }
+
+    #[test]
+    fn reduce_u128_handles_largest_product_test() {
+        let max = GOLDILOCKS_PRIME - 1;
+        let expected = ((BigInt::from(max) * BigInt::from(max)) % modulus())
+            .to_u64()
+            .unwrap();
+        assert_eq!(expected, reduce_u128(max as u128 * max as u128));
+    }
+}
@@ -256,6 +256,78 @@ impl IntegerRingPolynomial {
         ret
     }
 
+    // Below this degree, schoolbook's better cache behavior and lack of
+    // allocation/recombination overhead beats Karatsuba's better asymptotic complexity.
+    const KARATSUBA_THRESHOLD: usize = 32;
+
+    /// Karatsuba multiplication: splits each polynomial into a high and low half and
+    /// multiplies them with 3 recursive multiplications instead of schoolbook's 4,
+    /// trading that for some extra additions to recombine the pieces. Falls back to
+    /// `mul` below `KARATSUBA_THRESHOLD`, where the recombination overhead dominates.
+    pub fn mul_karatsuba(&self, other: &Self) -> Self {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Self::additive_identity();
+        }
+
+        if self.coefficients.len() < Self::KARATSUBA_THRESHOLD
+            || other.coefficients.len() < Self::KARATSUBA_THRESHOLD
+        {
+            return self.mul(other);
+        }
+
+        let split = std::cmp::max(self.coefficients.len(), other.coefficients.len()) / 2;
+
+        let (self_low, self_high) = Self::split_at(&self.coefficients, split);
+        let (other_low, other_high) = Self::split_at(&other.coefficients, split);
+
+        let low_product = self_low.mul_karatsuba(&other_low);
+        let high_product = self_high.mul_karatsuba(&other_high);
+        let mid_product = self_low.add(&self_high).mul_karatsuba(&other_low.add(&other_high));
+
+        // mid = (low + high) * (low + high) - low_product - high_product
+        //     = low*other_high + high*other_low
+        let mid = mid_product.sub(&low_product).sub(&high_product);
+
+        let mut result = low_product;
+        result = result.add(&mid.shift(split));
+        result = result.add(&high_product.shift(2 * split));
+        result.normalize();
+        result
+    }
+
+    /// Split `coefficients` into the low-order `at` coefficients and the remaining
+    /// high-order coefficients, each returned as its own polynomial.
+    fn split_at(coefficients: &[i128], at: usize) -> (Self, Self) {
+        if at >= coefficients.len() {
+            return (
+                Self {
+                    coefficients: coefficients.to_vec(),
+                },
+                Self::additive_identity(),
+            );
+        }
+
+        (
+            Self {
+                coefficients: coefficients[..at].to_vec(),
+            },
+            Self {
+                coefficients: coefficients[at..].to_vec(),
+            },
+        )
+    }
+
+    /// Multiply by `x^shift`, i.e. prepend `shift` zero coefficients.
+    fn shift(&self, shift: usize) -> Self {
+        if self.coefficients.is_empty() {
+            return Self::additive_identity();
+        }
+
+        let mut coefficients = vec![0i128; shift];
+        coefficients.extend_from_slice(&self.coefficients);
+        Self { coefficients }
+    }
+
     pub fn scalar_mul(&self, scalar: i128) -> Self {
         let mut coefficients = self.coefficients.clone();
         for i in 0..self.coefficients.len() {
@@ -368,4 +440,37 @@ mod test_integer_ring_polynomials {
         }
         assert_eq!(interpolation_result, pol);
     }
+
+    #[test]
+    fn mul_karatsuba_matches_schoolbook_mul_test() {
+        for (self_degree, other_degree) in [(0, 0), (1, 1), (31, 31), (32, 32), (63, 200), (200, 5)]
+        {
+            let self_pol = IntegerRingPolynomial {
+                coefficients: (0..=self_degree)
+                    .map(|_| rand::random::<i128>() % 1000)
+                    .collect(),
+            };
+            let other_pol = IntegerRingPolynomial {
+                coefficients: (0..=other_degree)
+                    .map(|_| rand::random::<i128>() % 1000)
+                    .collect(),
+            };
+
+            let mut schoolbook_product = self_pol.mul(&other_pol);
+            schoolbook_product.normalize();
+            let mut karatsuba_product = self_pol.mul_karatsuba(&other_pol);
+            karatsuba_product.normalize();
+            assert_eq!(schoolbook_product, karatsuba_product);
+        }
+    }
+
+    #[test]
+    fn mul_karatsuba_of_zero_polynomial_is_zero_test() {
+        let zero = IntegerRingPolynomial::additive_identity();
+        let pol = IntegerRingPolynomial {
+            coefficients: vec![1, 2, 3],
+        };
+        assert_eq!(zero, zero.mul_karatsuba(&pol));
+        assert_eq!(zero, pol.mul_karatsuba(&zero));
+    }
 }
@@ -1,25 +1,37 @@
+use crate::shared_math::ntt::intt;
 use crate::shared_math::other::{bigint, log_2_ceil};
 use crate::shared_math::polynomial::Polynomial;
 use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
 use crate::shared_math::prime_field_element_big::{PrimeFieldBig, PrimeFieldElementBig};
-use crate::shared_math::prime_field_polynomial::PrimeFieldPolynomial;
-use crate::util_types::merkle_tree::{MerkleTree, PartialAuthenticationPath};
+use crate::util_types::merkle_tree::{MerkleTree, Node, PartialAuthenticationPath};
 use crate::utils::{blake3_digest, get_index_from_bytes};
 use num_bigint::BigInt;
 use num_traits::One;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display};
+use std::io::{self, Read, Write};
 use std::result::Result;
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum ValidationError {
-    BadMerkleProof,
+    /// A Merkle opening failed to verify. `index` is the position (within that
+    /// round's `indices`/proof slices, not a leaf index) of the first opening
+    /// `MerkleTree::verify_multi_proof_detailed` found to be bad.
+    BadMerkleProof { index: usize },
     BadSizedProof,
+    /// A `challenge_hash_preimages` entry after the first wasn't the previous entry with
+    /// the corresponding round's Merkle root appended (the first entry is not checked),
+    /// or `index_picker_preimage` didn't extend the last `challenge_hash_preimages` entry with the last round's root. A
+    /// malicious prover could otherwise submit preimages disconnected from the actual
+    /// committed roots, breaking Fiat-Shamir soundness.
+    InconsistentTranscript,
+    InsufficientProofOfWork,
     NonPostiveRoundCount,
     NotColinear,
     LastIterationTooHighDegree,
@@ -35,6 +47,109 @@ impl fmt::Display for MyError {
     }
 }
 
+/// Format version written by `prover_shared`/`serialize_to` as the first byte of a
+/// serialized proof, and checked by `from_serialization` before parsing anything else.
+/// Bump this whenever the wire format changes incompatibly.
+const PROOF_FORMAT_VERSION: u8 = 1;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DeserializationError {
+    /// The leading format-version byte didn't match `PROOF_FORMAT_VERSION`.
+    VersionMismatch { expected: u8, got: u8 },
+    /// The buffer ended before the length-prefixed proof body did, i.e. the
+    /// serialization was truncated (or its length header is corrupt).
+    Truncated,
+}
+
+impl Error for DeserializationError {}
+
+impl fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Deserialization error for LowDegreeProof: {:?}", self)
+    }
+}
+
+/// Format version for the compact fixed-width encoding written by
+/// `LowDegreeProof::<BigInt>::serialize_compact_to`. Unlike `PROOF_FORMAT_VERSION`,
+/// every `BigInt` field element (the offset, the primitive root of unity, and every
+/// revealed codeword value in `ab_proofs`/`c_proofs`) is packed into a fixed
+/// `modulus_byte_len(modulus)` bytes instead of bincode's variable-length-prefixed
+/// framing, which is the bulk of a proof's size once a round reveals many values
+/// under a modulus much smaller than a `BigInt`'s native limb width.
+const PROOF_FORMAT_VERSION_COMPACT: u8 = 2;
+
+/// Number of bytes needed to hold any value in `0..modulus`, e.g. `3` for the
+/// 65537 modulus: `ceil(log2(65537) / 8) == 3`.
+fn modulus_byte_len(modulus: &BigInt) -> usize {
+    (modulus.bits() as usize + 7) / 8
+}
+
+/// Encode `value` as `byte_len` little-endian bytes. `value` must already be reduced
+/// mod a modulus with `modulus_byte_len(modulus) == byte_len`, which every `BigInt`
+/// field element stored in a `LowDegreeProof` is.
+fn encode_compact_bigint(value: &BigInt, byte_len: usize) -> Vec<u8> {
+    let (_, mut bytes) = value.to_bytes_le();
+    bytes.resize(byte_len, 0);
+    bytes
+}
+
+fn decode_compact_bigint(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_le(num_bigint::Sign::Plus, bytes)
+}
+
+fn encode_compact_path(path: &PartialAuthenticationPath<BigInt>, byte_len: usize) -> Vec<u8> {
+    let mut buf = vec![];
+    buf.extend_from_slice(&(path.0.len() as u16).to_le_bytes());
+    for node in &path.0 {
+        match node {
+            None => buf.push(0),
+            Some(n) => {
+                buf.push(1);
+                buf.extend_from_slice(&n.hash());
+                match &n.value {
+                    None => buf.push(0),
+                    Some(v) => {
+                        buf.push(1);
+                        buf.extend_from_slice(&encode_compact_bigint(v, byte_len));
+                    }
+                }
+            }
+        }
+    }
+    buf
+}
+
+fn decode_compact_path(
+    bytes: &[u8],
+    index: &mut usize,
+    byte_len: usize,
+) -> PartialAuthenticationPath<BigInt> {
+    let len = u16::from_le_bytes(bytes[*index..*index + 2].try_into().unwrap()) as usize;
+    *index += 2;
+    let mut nodes = Vec::with_capacity(len);
+    for _ in 0..len {
+        let has_node = bytes[*index];
+        *index += 1;
+        if has_node == 0 {
+            nodes.push(None);
+            continue;
+        }
+        let hash: [u8; 32] = bytes[*index..*index + 32].try_into().unwrap();
+        *index += 32;
+        let has_value = bytes[*index];
+        *index += 1;
+        let value = if has_value == 0 {
+            None
+        } else {
+            let v = decode_compact_bigint(&bytes[*index..*index + byte_len]);
+            *index += byte_len;
+            Some(v)
+        };
+        nodes.push(Some(Node::new(value, hash)));
+    }
+    PartialAuthenticationPath(nodes)
+}
+
 impl Error for ValidationError {}
 
 impl fmt::Display for ValidationError {
@@ -45,8 +160,33 @@ impl fmt::Display for ValidationError {
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ProveError {
-    BadMaxDegreeValue,
-    NonPostiveRoundCount,
+    BadFoldingFactor,
+    /// `max_degree + 1` wasn't a power of two. Carries the offending value so a
+    /// caller failing deep in a pipeline doesn't have to re-derive it by hand.
+    BadMaxDegreeValue { max_degree: u32 },
+    /// `codeword.len()` wasn't a power of two, or was less than `2 * (max_degree + 1)`.
+    /// `get_rounds_count` assumes `codeword_size` is a clean power-of-two multiple of
+    /// `max_degree + 1`; anything else makes its integer division silently misbehave.
+    CodewordSizeMismatch,
+    /// `max_degree` was `0`. FRI as implemented here always folds the codeword at
+    /// least once, so it has no trivial round for committing a bare constant;
+    /// callers that need to prove a constant should pick `max_degree = 1` instead.
+    MaxDegreeTooSmall,
+    /// `get_rounds_count` computed zero rounds for these parameters. Carries the
+    /// inputs it was given so the offending combination doesn't have to be
+    /// re-derived by hand.
+    NonPostiveRoundCount {
+        codeword_size: u32,
+        max_degree: u32,
+        s: u32,
+    },
+    SecurityLevelTooHigh,
+    /// `prover_batched_i128` was called with an empty `codewords` slice. There is no
+    /// sensible random linear combination of zero codewords.
+    NoCodewordsProvided,
+    /// `prover_batched_i128`'s codewords didn't all share the same length, so they
+    /// can't be combined pointwise over a common domain.
+    MismatchedCodewordLengths,
 }
 
 impl Error for ProveError {}
@@ -74,6 +214,15 @@ where
     max_degree: u32,
     max_degree_of_last_round: u32,
     pub merkle_roots: Vec<[u8; 32]>,
+    /// The coset offset the codeword was evaluated over. `1` for plain subgroup FRI.
+    pub offset: T,
+    /// Number of leading zero bits `pow_nonce` must produce; see `grind_pow_nonce`.
+    /// `0` disables grinding, in which case `pow_nonce` is unused and always `0`.
+    pow_bits: u8,
+    pow_nonce: u64,
+    /// How many-fold the codeword is reduced by in each round. A power of two;
+    /// `2` (plain halving) unless the prover was asked for a different value.
+    folding_factor: u8,
     primitive_root_of_unity: T,
     rounds_count: u8,
     pub s: u32,
@@ -85,6 +234,7 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
         round: u8,
         num_locations: u32,
         full_codeword_side: u32,
+        pow_nonce: u64,
     ) -> Option<Vec<(usize, usize, usize)>> {
         let half_code_word_size = full_codeword_side as usize >> (round + 1);
 
@@ -101,6 +251,7 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
         }
 
         let mut hash_preimage_clone = index_picker_preimage.to_vec();
+        hash_preimage_clone.extend_from_slice(&pow_nonce.to_le_bytes());
         hash_preimage_clone.push(round);
         let mut abc_indices: Vec<(usize, usize, usize)> = vec![];
         if num_locations > half_code_word_size as u32 / 2 {
@@ -142,6 +293,7 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
             round,
             self.s,
             self.codeword_size,
+            self.pow_nonce,
         )
     }
 
@@ -160,31 +312,188 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
 
         Some(ab_indices)
     }
+
+    /// Downsize the proof's security level after the fact by keeping only the first
+    /// `new_s` colinearity openings of each round, so a smaller proof can still be
+    /// verified at `new_s`. This is only a truncation: it cannot increase security.
+    ///
+    /// Each round's `ab_proofs`/`c_proofs` are pruned together as one `new_s`-sized
+    /// batch (`MerkleTree::get_multi_proof`), so a node kept past index `new_s` may
+    /// only be present because it's reconstructable from an opening beyond `new_s`
+    /// that's being dropped here. Slicing the original batch naively would carry that
+    /// gap forward into a proof that no longer verifies on its own, so instead each
+    /// round's kept openings are re-pruned from scratch via
+    /// `MerkleTree::reprune_multi_proof`, using the full round's proof (guaranteed
+    /// self-sufficient as a whole) as the source of truth for any node data the
+    /// smaller batch still needs.
+    ///
+    /// This assumes the first `new_s` indices `get_abc_indices_internal` picked for
+    /// `self.s` are the same indices it would pick from scratch for `new_s` -- true
+    /// as long as both stay in that function's "many more indices available than
+    /// requested" mode for every round, i.e. `self.s` doesn't exceed roughly a
+    /// quarter of the smallest round's codeword. A proof with so few rounds left
+    /// relative to `s` that this doesn't hold can't be safely downsized by slicing
+    /// its existing openings; doing so regardless would silently point the reduced
+    /// proof's Merkle openings at the wrong leaves.
+    pub fn reduce_security(&self, new_s: u32) -> Result<LowDegreeProof<U>, ProveError> {
+        if new_s > self.s {
+            return Err(ProveError::SecurityLevelTooHigh);
+        }
+
+        let ab_proofs: Vec<Vec<PartialAuthenticationPath<U>>> = (0..self.rounds_count)
+            .map(|round| {
+                let ab_indices = self.get_ab_indices(round).unwrap();
+                MerkleTree::reprune_multi_proof(
+                    &ab_indices,
+                    &self.ab_proofs[round as usize],
+                    &ab_indices[0..2 * new_s as usize],
+                )
+            })
+            .collect();
+        let c_proofs: Vec<Vec<PartialAuthenticationPath<U>>> = (0..self.rounds_count)
+            .map(|round| {
+                let c_indices: Vec<usize> = self
+                    .get_abc_indices(round)
+                    .unwrap()
+                    .iter()
+                    .map(|x| x.2)
+                    .collect();
+                MerkleTree::reprune_multi_proof(
+                    &c_indices,
+                    &self.c_proofs[round as usize],
+                    &c_indices[0..new_s as usize],
+                )
+            })
+            .collect();
+
+        Ok(LowDegreeProof::<U> {
+            ab_proofs,
+            challenge_hash_preimages: self.challenge_hash_preimages.clone(),
+            codeword_size: self.codeword_size,
+            c_proofs,
+            index_picker_preimage: self.index_picker_preimage.clone(),
+            max_degree: self.max_degree,
+            max_degree_of_last_round: self.max_degree_of_last_round,
+            merkle_roots: self.merkle_roots.clone(),
+            offset: self.offset.clone(),
+            pow_bits: self.pow_bits,
+            pow_nonce: self.pow_nonce,
+            folding_factor: self.folding_factor,
+            primitive_root_of_unity: self.primitive_root_of_unity.clone(),
+            rounds_count: self.rounds_count,
+            s: new_s,
+        })
+    }
+}
+
+/// Search for the smallest nonce such that hashing `preimage || nonce` together
+/// produces at least `pow_bits` leading zero bits. This is the proof-of-work
+/// grinding step: requiring the prover to spend `2^pow_bits` work (on average)
+/// to find such a nonce lets a smaller `s` be used for the same soundness,
+/// since a cheating prover can no longer freely resample query indices until
+/// one happens to work in their favor. `pow_bits = 0` is free: nonce `0`
+/// always satisfies the (vacuous) condition.
+fn grind_pow_nonce(preimage: &[u8], pow_bits: u8) -> u64 {
+    let mut nonce: u64 = 0;
+    while !verify_pow_nonce(preimage, nonce, pow_bits) {
+        nonce += 1;
+    }
+    nonce
+}
+
+/// Counterpart to `grind_pow_nonce`: checks that `preimage || nonce` hashes to
+/// at least `pow_bits` leading zero bits.
+fn verify_pow_nonce(preimage: &[u8], nonce: u64, pow_bits: u8) -> bool {
+    if pow_bits == 0 {
+        return true;
+    }
+
+    let mut preimage_with_nonce = preimage.to_vec();
+    preimage_with_nonce.extend_from_slice(&nonce.to_le_bytes());
+    let hash = blake3::hash(preimage_with_nonce.as_slice());
+    leading_zero_bits(hash.as_bytes()) >= pow_bits as u32
+}
+
+/// Check that `challenge_hash_preimages` is a running transcript: preimage `i + 1` must
+/// equal preimage `i` with round `i + 1`'s Merkle root appended, and
+/// `index_picker_preimage` must equal the last preimage with the final round's Merkle
+/// root appended. A malicious prover could otherwise submit `challenge_hash_preimages`
+/// disconnected from the committed roots, deriving challenges that don't actually commit
+/// to the codewords the verifier checks against.
+fn verify_transcript_chaining<T: Clone + Debug + PartialEq + Serialize>(
+    proof: &LowDegreeProof<T>,
+) -> bool {
+    for i in 0..proof.challenge_hash_preimages.len().saturating_sub(1) {
+        let mut expected = proof.challenge_hash_preimages[i].clone();
+        expected.extend_from_slice(&proof.merkle_roots[i + 1]);
+        if expected != proof.challenge_hash_preimages[i + 1] {
+            return false;
+        }
+    }
+
+    if let Some(last_preimage) = proof.challenge_hash_preimages.last() {
+        let mut expected = last_preimage.clone();
+        expected.extend_from_slice(&proof.merkle_roots[proof.merkle_roots.len() - 1]);
+        if expected != proof.index_picker_preimage {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn leading_zero_bits(bytes: &[u8]) -> u32 {
+    let mut count = 0u32;
+    for &byte in bytes {
+        if byte == 0 {
+            count += 8;
+        } else {
+            count += byte.leading_zeros();
+            break;
+        }
+    }
+    count
 }
 
+/// Returns `None` if `folding_factor` isn't a power of two greater than one, or if any
+/// intermediate computation would otherwise overflow or underflow. The prover checks
+/// its folding factor and max degree first and reports a `None` here (too many
+/// colinearity checks for the codeword) as `ProveError::NonPostiveRoundCount`;
+/// `from_serialization` calls it with attacker-controlled values
+/// read off the wire, where `None` means the proof is malformed.
 fn get_rounds_count(
     codeword_size: u32,
     max_degree: u32,
     number_of_colinearity_checks: u32,
-) -> (u8, u32) {
+    folding_factor: u8,
+) -> Option<(u8, u32)> {
     // Find number of rounds from max_degree. If expansion factor is less than the security level (s),
-    // then we need to stop the iteration when the remaining codeword (that is halved in each round)
+    // then we need to stop the iteration when the remaining codeword (that is folded in each round)
     // has a length smaller than the security level. Otherwise, we couldn't test enough points for the
     // remaining code word.
     // codeword_size *should* be a multiple of `max_degree + 1`
-    // rounds_count is the number of times the code word length is halved
-    let expansion_factor: u32 = codeword_size / (max_degree + 1);
-    let mut rounds_count = log_2_ceil(max_degree as u64 + 1) as u8;
+    // `log2_folding_factor` rounds_count is the number of times the code word length is
+    // reduced by `folding_factor` (so rounds_count * log2_folding_factor binary halvings)
+    if !folding_factor.is_power_of_two() || folding_factor < 2 {
+        return None;
+    }
+    let log2_folding_factor = folding_factor.trailing_zeros() as u8;
+    let expansion_factor: u32 = codeword_size / max_degree.checked_add(1)?;
+    let mut rounds_count = (log_2_ceil(max_degree as u64 + 1) as u8).checked_div(log2_folding_factor)?;
     let mut max_degree_of_last_round = 0u32;
     if expansion_factor < number_of_colinearity_checks {
-        let num_missed_rounds = log_2_ceil(
+        let num_missed_binary_rounds = log_2_ceil(
             (number_of_colinearity_checks as f64 / expansion_factor as f64).ceil() as u64,
         ) as u8;
-        rounds_count -= num_missed_rounds;
-        max_degree_of_last_round = 2u32.pow(num_missed_rounds as u32) - 1;
+        let num_missed_rounds =
+            (num_missed_binary_rounds + log2_folding_factor - 1) / log2_folding_factor;
+        rounds_count = rounds_count.checked_sub(num_missed_rounds)?;
+        max_degree_of_last_round = 2u32
+            .checked_pow(num_missed_rounds as u32 * log2_folding_factor as u32)?
+            .checked_sub(1)?;
     }
 
-    (rounds_count, max_degree_of_last_round)
+    Some((rounds_count, max_degree_of_last_round))
 }
 
 impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowDegreeProof<U> {
@@ -192,53 +501,127 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
         serialization: Vec<u8>,
         start_index: usize,
     ) -> Result<(LowDegreeProof<U>, usize), Box<dyn Error>> {
-        let mut index = start_index;
+        if start_index >= serialization.len() {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+        let version = serialization[start_index];
+        if version != PROOF_FORMAT_VERSION {
+            return Err(Box::new(DeserializationError::VersionMismatch {
+                expected: PROOF_FORMAT_VERSION,
+                got: version,
+            }));
+        }
+        let mut index = start_index + 1;
+        if index + 4 > serialization.len() {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+        let body_length: u32 = bincode::deserialize(&serialization[index..index + 4])?;
+        index += 4;
+        let body_start = index;
+        let body_end = body_start + body_length as usize;
+        if serialization.len() < body_end {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+
+        // Every field below is length-prefixed and lives inside the body; check each
+        // read against `body_end` before slicing so a corrupted/truncated body returns
+        // `Truncated` instead of panicking on an out-of-range slice.
+        let need = |index: usize, len: usize| -> Result<(), Box<dyn Error>> {
+            if index + len > body_end {
+                return Err(Box::new(DeserializationError::Truncated));
+            }
+            Ok(())
+        };
+
+        need(index, 4)?;
         let codeword_size: u32 = bincode::deserialize(&serialization[index..index + 4])?;
         index += 4;
+        need(index, 4)?;
         let max_degree: u32 = bincode::deserialize(&serialization[index..index + 4])?;
         index += 4;
+        need(index, 4)?;
         let number_of_colinearity_checks: u32 =
             bincode::deserialize(&serialization[index..index + 4])?;
         index += 4;
+        need(index, 2)?;
         let size_of_root: u16 = bincode::deserialize(&serialization[index..index + 2])?;
         index += 2;
+        need(index, size_of_root as usize)?;
         let primitive_root_of_unity: U =
             bincode::deserialize(&serialization[index..index + size_of_root as usize])?;
         index += size_of_root as usize;
-
-        let (rounds_count, max_degree_of_last_round) =
-            get_rounds_count(codeword_size, max_degree, number_of_colinearity_checks);
+        need(index, 2)?;
+        let size_of_offset: u16 = bincode::deserialize(&serialization[index..index + 2])?;
+        index += 2;
+        need(index, size_of_offset as usize)?;
+        let offset: U = bincode::deserialize(&serialization[index..index + size_of_offset as usize])?;
+        index += size_of_offset as usize;
+        need(index, 1)?;
+        let pow_bits: u8 = bincode::deserialize(&serialization[index..index + 1])?;
+        index += 1;
+        need(index, 1)?;
+        let folding_factor: u8 = bincode::deserialize(&serialization[index..index + 1])?;
+        index += 1;
+
+        let (rounds_count, max_degree_of_last_round) = match get_rounds_count(
+            codeword_size,
+            max_degree,
+            number_of_colinearity_checks,
+            folding_factor,
+        ) {
+            Some(counts) => counts,
+            // `None` means one of the header fields above is nonsensical (e.g. a
+            // `folding_factor` that isn't a power of two) -- that's indistinguishable
+            // from a corrupted/truncated proof from the caller's point of view.
+            None => return Err(Box::new(DeserializationError::Truncated)),
+        };
         if rounds_count < 1 {
             return Err(Box::new(ValidationError::NonPostiveRoundCount));
         }
 
         let rounds_count_usize = rounds_count as usize;
 
+        // Preimages are reconstructed as prefixes of the proof's own body (everything
+        // from `body_start` on), since that's exactly the buffer `prover_shared`/
+        // `prover_*_coset` hash from -- it never includes the version/length header or
+        // any unrelated bytes preceding `start_index`.
+        if (rounds_count_usize + 1) * 32 + index > body_end {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
         let challenge_hash_preimages: Vec<Vec<u8>> = (0..rounds_count_usize)
-            .map(|i| serialization[0..((i + 1) * 32 + index)].to_vec())
+            .map(|i| serialization[body_start..((i + 1) * 32 + index)].to_vec())
             .collect();
         let index_picker_preimage =
-            serialization[0..((rounds_count_usize + 1) * 32 + index)].to_vec();
+            serialization[body_start..((rounds_count_usize + 1) * 32 + index)].to_vec();
         let mut merkle_roots: Vec<[u8; 32]> = Vec::with_capacity(rounds_count_usize + 1);
         for _ in 0usize..(rounds_count_usize + 1) {
+            need(index, 32)?;
             let root: [u8; 32] = serialization[index..index + 32].try_into()?;
             index += 32;
             merkle_roots.push(root);
         }
 
+        need(index, 8)?;
+        let pow_nonce: u64 = bincode::deserialize(&serialization[index..index + 8])?;
+        index += 8;
+
         let mut c_proofs: Vec<Vec<PartialAuthenticationPath<U>>> =
             Vec::with_capacity(rounds_count_usize);
         let mut ab_proofs: Vec<Vec<PartialAuthenticationPath<U>>> =
             Vec::with_capacity(rounds_count_usize);
         for _ in 0..rounds_count {
+            need(index, 2)?;
             let mut proof_size: u16 = bincode::deserialize(&serialization[index..index + 2])?;
             index += 2;
+            need(index, proof_size as usize)?;
             let c_proof: Vec<PartialAuthenticationPath<U>> =
                 bincode::deserialize_from(&serialization[index..index + proof_size as usize])?;
             index += proof_size as usize;
             c_proofs.push(c_proof);
+            need(index, 2)?;
             proof_size = bincode::deserialize(&serialization[index..index + 2])?;
             index += 2;
+            need(index, proof_size as usize)?;
             let ab_proof: Vec<PartialAuthenticationPath<U>> =
                 bincode::deserialize_from(&serialization[index..index + proof_size as usize])?;
             index += proof_size as usize;
@@ -254,6 +637,10 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
                 max_degree,
                 max_degree_of_last_round,
                 merkle_roots,
+                offset,
+                pow_bits,
+                pow_nonce,
+                folding_factor,
                 primitive_root_of_unity,
                 rounds_count,
                 s: number_of_colinearity_checks,
@@ -261,8 +648,277 @@ impl<U: Clone + Debug + Display + DeserializeOwned + PartialEq + Serialize> LowD
             index,
         ))
     }
+
+    /// Write this proof to `w` using the exact byte layout `prover_*` builds up in its
+    /// `output: &mut Vec<u8>` argument, so the result round-trips through
+    /// `from_serialization`/`deserialize_from`. Useful for writing a proof straight to a
+    /// socket or file instead of buffering it in memory first.
+    pub fn serialize_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        // Buffer the body first, same as `prover_shared`/`prover_*_coset`, since the
+        // version+length header written below needs the body's final length up front.
+        let mut body: Vec<u8> = vec![];
+        body.extend_from_slice(&bincode::serialize(&self.codeword_size).unwrap());
+        body.extend_from_slice(&bincode::serialize(&self.max_degree).unwrap());
+        body.extend_from_slice(&bincode::serialize(&self.s).unwrap());
+
+        let root_serialization = bincode::serialize(&self.primitive_root_of_unity).unwrap();
+        body.extend_from_slice(&bincode::serialize(&(root_serialization.len() as u16)).unwrap());
+        body.extend_from_slice(&root_serialization);
+
+        let offset_serialization = bincode::serialize(&self.offset).unwrap();
+        body.extend_from_slice(&bincode::serialize(&(offset_serialization.len() as u16)).unwrap());
+        body.extend_from_slice(&offset_serialization);
+
+        body.extend_from_slice(&bincode::serialize(&self.pow_bits).unwrap());
+        body.extend_from_slice(&bincode::serialize(&self.folding_factor).unwrap());
+
+        for root in &self.merkle_roots {
+            body.extend_from_slice(root);
+        }
+
+        body.extend_from_slice(&bincode::serialize(&self.pow_nonce).unwrap());
+
+        for i in 0..self.rounds_count as usize {
+            let c_paths_encoded = bincode::serialize(&self.c_proofs[i]).unwrap();
+            body.extend_from_slice(&bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
+            body.extend_from_slice(&c_paths_encoded);
+
+            let ab_paths_encoded = bincode::serialize(&self.ab_proofs[i]).unwrap();
+            body.extend_from_slice(&bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
+            body.extend_from_slice(&ab_paths_encoded);
+        }
+
+        w.write_all(&[PROOF_FORMAT_VERSION])?;
+        w.write_all(&bincode::serialize(&(body.len() as u32)).unwrap())?;
+        w.write_all(&body)?;
+
+        Ok(())
+    }
+
+    /// Read a proof written by `serialize_to` back from `r`. Mirrors
+    /// `from_serialization`'s byte layout exactly, just sourced from a `Read` instead of
+    /// an in-memory buffer that's already fully received.
+    pub fn deserialize_from<R: Read>(r: &mut R) -> Result<Self, Box<dyn Error>> {
+        let mut bytes = vec![];
+        r.read_to_end(&mut bytes)?;
+        let (proof, _) = Self::from_serialization(bytes, 0)?;
+        Ok(proof)
+    }
+}
+
+impl LowDegreeProof<BigInt> {
+    /// Recompute the per-round Fiat-Shamir challenges from `challenge_hash_preimages`,
+    /// mirroring the derivation `verify_bigint_with_witness` does internally. Useful for
+    /// inspecting challenges when testing soundness or building a higher-level protocol
+    /// on top of FRI.
+    pub fn recompute_challenges(&self, modulus: &BigInt) -> Vec<BigInt> {
+        self.challenge_hash_preimages
+            .iter()
+            .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
+            .map(|hash| PrimeFieldElementBig::from_bytes_raw(modulus, &hash[0..16]))
+            .collect()
+    }
+
+    /// Wraps `primitive_root_of_unity` into a field element of `field`, rather than
+    /// leaving it a bare `BigInt` that every caller has to re-bind to a field before
+    /// doing any arithmetic with it.
+    pub fn root_of_unity<'a>(&self, field: &'a PrimeFieldBig) -> PrimeFieldElementBig<'a> {
+        PrimeFieldElementBig::new(self.primitive_root_of_unity.clone(), field)
+    }
+
+    /// Write this proof using the compact fixed-width encoding: every `BigInt` field
+    /// element (the offset, the primitive root of unity, and the revealed codeword
+    /// values inside `ab_proofs`/`c_proofs`) is packed into `modulus_byte_len(modulus)`
+    /// bytes instead of bincode's variable-length-prefixed framing. Smaller on the wire
+    /// for a modulus much narrower than a `BigInt`'s native limb width, at the cost of
+    /// needing `modulus` again on the way back in (see `deserialize_compact_from`).
+    pub fn serialize_compact_to<W: Write>(&self, w: &mut W, modulus: &BigInt) -> io::Result<()> {
+        let byte_len = modulus_byte_len(modulus);
+
+        let mut body: Vec<u8> = vec![];
+        body.extend_from_slice(&self.codeword_size.to_le_bytes());
+        body.extend_from_slice(&self.max_degree.to_le_bytes());
+        body.extend_from_slice(&self.s.to_le_bytes());
+        body.push(self.pow_bits);
+        body.push(self.folding_factor);
+        body.push(self.rounds_count);
+        body.extend_from_slice(&self.max_degree_of_last_round.to_le_bytes());
+        body.extend_from_slice(&encode_compact_bigint(&self.primitive_root_of_unity, byte_len));
+        body.extend_from_slice(&encode_compact_bigint(&self.offset, byte_len));
+        for root in &self.merkle_roots {
+            body.extend_from_slice(root);
+        }
+        body.extend_from_slice(&self.pow_nonce.to_le_bytes());
+
+        for preimage in &self.challenge_hash_preimages {
+            body.extend_from_slice(&(preimage.len() as u16).to_le_bytes());
+            body.extend_from_slice(preimage);
+        }
+        body.extend_from_slice(&(self.index_picker_preimage.len() as u16).to_le_bytes());
+        body.extend_from_slice(&self.index_picker_preimage);
+
+        for round in 0..self.rounds_count as usize {
+            body.extend_from_slice(&(self.c_proofs[round].len() as u16).to_le_bytes());
+            for path in &self.c_proofs[round] {
+                body.extend_from_slice(&encode_compact_path(path, byte_len));
+            }
+            body.extend_from_slice(&(self.ab_proofs[round].len() as u16).to_le_bytes());
+            for path in &self.ab_proofs[round] {
+                body.extend_from_slice(&encode_compact_path(path, byte_len));
+            }
+        }
+
+        w.write_all(&[PROOF_FORMAT_VERSION_COMPACT])?;
+        w.write_all(&(body.len() as u32).to_le_bytes())?;
+        w.write_all(&body)?;
+
+        Ok(())
+    }
+
+    /// Read a proof written by `serialize_compact_to` back from `r`. `modulus` must be
+    /// the same modulus the proof was serialized with, since the compact encoding
+    /// relies on it to know how many bytes each field element takes.
+    pub fn deserialize_compact_from<R: Read>(
+        r: &mut R,
+        modulus: &BigInt,
+    ) -> Result<Self, Box<dyn Error>> {
+        let byte_len = modulus_byte_len(modulus);
+        let mut bytes = vec![];
+        r.read_to_end(&mut bytes)?;
+
+        if bytes.is_empty() || bytes[0] != PROOF_FORMAT_VERSION_COMPACT {
+            return Err(Box::new(DeserializationError::VersionMismatch {
+                expected: PROOF_FORMAT_VERSION_COMPACT,
+                got: *bytes.first().unwrap_or(&0),
+            }));
+        }
+        let mut index = 1;
+        if index + 4 > bytes.len() {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+        let body_length = u32::from_le_bytes(bytes[index..index + 4].try_into()?) as usize;
+        index += 4;
+        if bytes.len() < index + body_length {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+
+        let codeword_size = u32::from_le_bytes(bytes[index..index + 4].try_into()?);
+        index += 4;
+        let max_degree = u32::from_le_bytes(bytes[index..index + 4].try_into()?);
+        index += 4;
+        let s = u32::from_le_bytes(bytes[index..index + 4].try_into()?);
+        index += 4;
+        let pow_bits = bytes[index];
+        index += 1;
+        let folding_factor = bytes[index];
+        index += 1;
+        let rounds_count = bytes[index];
+        index += 1;
+        let max_degree_of_last_round = u32::from_le_bytes(bytes[index..index + 4].try_into()?);
+        index += 4;
+        let primitive_root_of_unity = decode_compact_bigint(&bytes[index..index + byte_len]);
+        index += byte_len;
+        let offset = decode_compact_bigint(&bytes[index..index + byte_len]);
+        index += byte_len;
+
+        let mut merkle_roots = Vec::with_capacity(rounds_count as usize + 1);
+        for _ in 0..rounds_count as usize + 1 {
+            let root: [u8; 32] = bytes[index..index + 32].try_into()?;
+            index += 32;
+            merkle_roots.push(root);
+        }
+        let pow_nonce = u64::from_le_bytes(bytes[index..index + 8].try_into()?);
+        index += 8;
+
+        let mut challenge_hash_preimages = Vec::with_capacity(rounds_count as usize);
+        for _ in 0..rounds_count as usize {
+            let len = u16::from_le_bytes(bytes[index..index + 2].try_into()?) as usize;
+            index += 2;
+            challenge_hash_preimages.push(bytes[index..index + len].to_vec());
+            index += len;
+        }
+        let index_picker_preimage_len = u16::from_le_bytes(bytes[index..index + 2].try_into()?) as usize;
+        index += 2;
+        let index_picker_preimage = bytes[index..index + index_picker_preimage_len].to_vec();
+        index += index_picker_preimage_len;
+
+        let mut c_proofs = Vec::with_capacity(rounds_count as usize);
+        let mut ab_proofs = Vec::with_capacity(rounds_count as usize);
+        for _ in 0..rounds_count as usize {
+            let c_count = u16::from_le_bytes(bytes[index..index + 2].try_into()?) as usize;
+            index += 2;
+            let mut c_round = Vec::with_capacity(c_count);
+            for _ in 0..c_count {
+                c_round.push(decode_compact_path(&bytes, &mut index, byte_len));
+            }
+            c_proofs.push(c_round);
+
+            let ab_count = u16::from_le_bytes(bytes[index..index + 2].try_into()?) as usize;
+            index += 2;
+            let mut ab_round = Vec::with_capacity(ab_count);
+            for _ in 0..ab_count {
+                ab_round.push(decode_compact_path(&bytes, &mut index, byte_len));
+            }
+            ab_proofs.push(ab_round);
+        }
+
+        Ok(LowDegreeProof::<BigInt> {
+            ab_proofs,
+            challenge_hash_preimages,
+            codeword_size,
+            c_proofs,
+            index_picker_preimage,
+            max_degree,
+            max_degree_of_last_round,
+            merkle_roots,
+            offset,
+            pow_bits,
+            pow_nonce,
+            folding_factor,
+            primitive_root_of_unity,
+            rounds_count,
+            s,
+        })
+    }
+}
+
+impl LowDegreeProof<i128> {
+    /// Recompute the per-round Fiat-Shamir challenges from `challenge_hash_preimages`,
+    /// mirroring the derivation `verify_i128` does internally.
+    pub fn recompute_challenges(&self, modulus: i128) -> Vec<i128> {
+        self.challenge_hash_preimages
+            .iter()
+            .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
+            .map(|hash| PrimeFieldElement::from_bytes_raw(&modulus, &hash[0..16]))
+            .collect()
+    }
+
+    /// Wraps `primitive_root_of_unity` into a field element of `field`, rather than
+    /// leaving it a bare `i128` that every caller has to re-bind to a field before
+    /// doing any arithmetic with it.
+    pub fn root_of_unity<'a>(&self, field: &'a PrimeField) -> PrimeFieldElement<'a> {
+        PrimeFieldElement::new(self.primitive_root_of_unity, field)
+    }
+}
+
+impl LowDegreeProof<u64> {
+    /// Recompute the per-round Fiat-Shamir challenges from `challenge_hash_preimages`,
+    /// mirroring the derivation `verify_u64` does internally.
+    pub fn recompute_challenges(&self, modulus: u64) -> Vec<u64> {
+        self.challenge_hash_preimages
+            .iter()
+            .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
+            .map(|hash| from_bytes_raw_u64(modulus, &hash[0..16]))
+            .collect()
+    }
 }
 
+/// Verify a proof produced by `prover_bigint`/`prover_bigint_coset`: re-derive the
+/// round challenges from `proof.challenge_hash_preimages`, check the `ab`/`c`
+/// Merkle multi-proofs against `proof.merkle_roots`, and check colinearity of the
+/// sampled points in every round, finally checking that the last round's `c`
+/// values lie on a sufficiently low-degree polynomial.
+///
 // Thor wanted to program this for `PrimeFieldElementBig` instead of `BigInt` but
 // was unable to, since he could not deserialize a struct with a pointer, like
 // PrimeFieldElementBig has. So the solution is to provide the modulus, as a `BigInt`
@@ -271,6 +927,23 @@ pub fn verify_bigint(
     proof: LowDegreeProof<BigInt>,
     modulus: BigInt,
 ) -> Result<(), ValidationError> {
+    let field = PrimeFieldBig::new(modulus);
+    verify_bigint_with_witness(proof, &field).map(|_| ())
+}
+
+/// Same as `verify_bigint`, but on success also hands back the low-degree
+/// polynomial reconstructed from the last round's `c` values, instead of
+/// discarding it. Useful for debugging a failing/suspicious proof, or for a
+/// protocol built on top of FRI that needs the final constant/low-degree
+/// polynomial itself (e.g. to read off its coefficients).
+///
+/// Takes `field` by reference rather than a bare modulus so the returned
+/// `Polynomial<PrimeFieldElementBig<'a>>` can borrow it; `verify_bigint` builds a
+/// throwaway `PrimeFieldBig` internally since it only needs the pass/fail result.
+pub fn verify_bigint_with_witness<'a>(
+    proof: LowDegreeProof<BigInt>,
+    field: &'a PrimeFieldBig,
+) -> Result<Polynomial<PrimeFieldElementBig<'a>>, ValidationError> {
     if proof.rounds_count as usize != proof.ab_proofs.len()
         || proof.rounds_count as usize != proof.c_proofs.len()
         || proof.rounds_count as usize != proof.challenge_hash_preimages.len()
@@ -279,91 +952,132 @@ pub fn verify_bigint(
         return Err(ValidationError::BadSizedProof);
     }
 
-    let challenge_hashes: Vec<[u8; 32]> = proof
-        .challenge_hash_preimages
-        .iter()
-        .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
-        .collect();
-    let challenges: Vec<BigInt> = challenge_hashes
-        .iter()
-        .map(|x| PrimeFieldElementBig::from_bytes_raw(&modulus, &x[0..16]))
-        .collect();
+    if !verify_transcript_chaining(&proof) {
+        return Err(ValidationError::InconsistentTranscript);
+    }
+
+    if !verify_pow_nonce(&proof.index_picker_preimage, proof.pow_nonce, proof.pow_bits) {
+        return Err(ValidationError::InsufficientProofOfWork);
+    }
+
+    let modulus = field.q.clone();
+    let challenges: Vec<BigInt> = proof.recompute_challenges(&modulus);
     let mut primitive_root_of_unity = proof.primitive_root_of_unity.clone();
+    let mut offset = proof.offset.clone();
 
-    let field = PrimeFieldBig::new(modulus.clone());
-    let mut c_values: Vec<BigInt> = vec![];
+    // Borrowed rather than cloned: rebuilt every round but only the last round's
+    // values are read (in `c_points` below), so cloning `BigInt`s here on every
+    // round but the last would be pure waste.
+    let mut c_values: Vec<&BigInt> = vec![];
     let mut last_a_xs: Vec<PrimeFieldElementBig> = vec![];
     for (i, challenge_bigint) in challenges.iter().enumerate() {
-        let abc_indices_option = proof.get_abc_indices(i as u8);
-        let abc_indices = abc_indices_option.unwrap();
-        let c_indices = abc_indices.iter().map(|x| x.2).collect::<Vec<usize>>();
-        let mut ab_indices = Vec::<usize>::with_capacity(2 * abc_indices.len());
-        for (a, b, _) in abc_indices.iter() {
-            ab_indices.push(*a);
-            ab_indices.push(*b);
-        }
+        let (ab_indices, c_indices): (Vec<usize>, Vec<usize>) = if proof.folding_factor == 2 {
+            let abc_indices = proof.get_abc_indices(i as u8).unwrap();
+            let c_indices = abc_indices.iter().map(|x| x.2).collect::<Vec<usize>>();
+            let mut ab_indices = Vec::<usize>::with_capacity(2 * abc_indices.len());
+            for (a, b, _) in abc_indices.iter() {
+                ab_indices.push(*a);
+                ab_indices.push(*b);
+            }
+            (ab_indices, c_indices)
+        } else {
+            let k_ary_indices = get_k_ary_indices_internal(
+                &proof.index_picker_preimage,
+                i as u8,
+                proof.s,
+                proof.codeword_size,
+                proof.pow_nonce,
+                proof.folding_factor,
+            )
+            .unwrap();
+            let mut ab_indices = vec![];
+            let mut c_indices = vec![];
+            for (siblings, ci) in k_ary_indices.into_iter() {
+                ab_indices.extend(siblings);
+                c_indices.push(ci);
+            }
+            (ab_indices, c_indices)
+        };
 
         c_values = proof.c_proofs[i]
             .iter()
-            .map(|x| x.get_value())
-            .collect::<Vec<BigInt>>();
+            .map(|x| x.get_value_ref())
+            .collect::<Vec<&BigInt>>();
 
-        let valid_cs = MerkleTree::verify_multi_proof(
+        let cs_result = MerkleTree::verify_multi_proof_detailed(
             proof.merkle_roots[i + 1],
             &c_indices,
             &proof.c_proofs[i],
         );
-        let valid_abs =
-            MerkleTree::verify_multi_proof(proof.merkle_roots[i], &ab_indices, &proof.ab_proofs[i]);
-        if !valid_cs || !valid_abs {
+        let abs_result = MerkleTree::verify_multi_proof_detailed(
+            proof.merkle_roots[i],
+            &ab_indices,
+            &proof.ab_proofs[i],
+        );
+        if cs_result.is_err() || abs_result.is_err() {
             println!(
                 "Found invalidity of indices on iteration {}: y = {}, s = {}",
-                i, valid_cs, valid_abs
+                i,
+                cs_result.is_ok(),
+                abs_result.is_ok()
             );
             print!("Invalid proofs:");
-            if !valid_abs {
+            if abs_result.is_err() {
                 println!("{:?}", &proof.c_proofs[i]);
             }
-            if !valid_cs {
+            if cs_result.is_err() {
                 println!("{:?}", &proof.ab_proofs[i]);
             }
-            return Err(ValidationError::BadMerkleProof);
+            let index = cs_result.err().or_else(|| abs_result.err()).unwrap();
+            return Err(ValidationError::BadMerkleProof { index });
         }
 
-        let root = PrimeFieldElementBig::new(primitive_root_of_unity.clone(), &field);
+        let root = PrimeFieldElementBig::new(primitive_root_of_unity.clone(), field);
+        let offset_elem = PrimeFieldElementBig::new(offset.clone(), field);
+        let folding_factor = proof.folding_factor as usize;
         for j in 0..proof.s as usize {
-            let a_index = ab_indices[2 * j] as i128;
-            let a_x_bigint = root.mod_pow_raw(bigint(a_index));
-            let a_y_bigint: BigInt = proof.ab_proofs[i][2 * j].get_value();
-            let b_index = ab_indices[2 * j + 1] as i128;
-            let b_x_bigint = root.mod_pow_raw(bigint(b_index));
-            let b_y_bigint: BigInt = proof.ab_proofs[i][2 * j + 1].get_value();
+            let challenge = PrimeFieldElementBig::new(challenge_bigint.to_owned(), field);
             let c_y_bigint = proof.c_proofs[i][j].get_value();
-            let a_x = PrimeFieldElementBig::new(a_x_bigint.clone(), &field);
+            let c_y = PrimeFieldElementBig::new(c_y_bigint, field);
+
+            let mut points: Vec<(PrimeFieldElementBig, PrimeFieldElementBig)> = (0..folding_factor)
+                .map(|k| {
+                    let index = ab_indices[folding_factor * j + k] as i128;
+                    let x_bigint = (offset_elem.clone() * root.mod_pow(bigint(index))).value;
+                    let y_bigint: BigInt = proof.ab_proofs[i][folding_factor * j + k].get_value();
+                    (
+                        PrimeFieldElementBig::new(x_bigint, field),
+                        PrimeFieldElementBig::new(y_bigint, field),
+                    )
+                })
+                .collect();
 
-            // We need the a_x values from the last round when inspecting the
-            // last sample
+            // We need the first sibling's x-value from the last round when inspecting
+            // the last sample
             if i == proof.rounds_count as usize - 1usize {
-                last_a_xs.push(a_x.clone());
+                last_a_xs.push(points[0].0.clone());
             }
 
-            let a_y = PrimeFieldElementBig::new(a_y_bigint, &field);
-            let b_x = PrimeFieldElementBig::new(b_x_bigint, &field);
-            let b_y = PrimeFieldElementBig::new(b_y_bigint, &field);
-            let challenge = PrimeFieldElementBig::new(challenge_bigint.to_owned(), &field);
-            let c_y = PrimeFieldElementBig::new(c_y_bigint, &field);
-            if !Polynomial::are_colinear(&[(a_x, a_y), (b_x, b_y), (challenge, c_y)]) {
-                // println!(
-                //     "{{({},{}),({},{}),({},{})}} are not colinear",
-                //     a_x, a_y, b_x, b_y, challenge, c_y
-                // );
+            points.push((challenge, c_y));
+            if !Polynomial::lie_on_degree_n(&points, folding_factor - 1) {
                 println!("Failed to verify colinearity!");
                 return Err(ValidationError::NotColinear);
             }
         }
 
-        primitive_root_of_unity =
-            primitive_root_of_unity.clone() * primitive_root_of_unity.clone() % modulus.clone();
+        if proof.folding_factor == 2 {
+            primitive_root_of_unity =
+                primitive_root_of_unity.clone() * primitive_root_of_unity.clone() % modulus.clone();
+            offset = offset.clone() * offset.clone() % modulus.clone();
+        } else {
+            primitive_root_of_unity =
+                PrimeFieldElementBig::new(primitive_root_of_unity, field)
+                    .mod_pow(bigint(proof.folding_factor as i128))
+                    .value;
+            offset = PrimeFieldElementBig::new(offset, field)
+                .mod_pow(bigint(proof.folding_factor as i128))
+                .value;
+        }
     }
 
     // Base case: Verify that the values in the last merkle tree has a sufficiently low degree
@@ -373,8 +1087,8 @@ pub fn verify_bigint(
         .zip(last_a_xs.iter())
         .map(|(c_y, a_x)| {
             (
-                a_x.clone().mod_pow(bigint(2)),
-                PrimeFieldElementBig::new(c_y.clone(), &field),
+                a_x.clone().mod_pow(bigint(proof.folding_factor as i128)),
+                PrimeFieldElementBig::new((**c_y).clone(), field),
             )
         })
         .collect();
@@ -385,144 +1099,350 @@ pub fn verify_bigint(
         return Err(ValidationError::LastIterationTooHighDegree);
     }
 
-    Ok(())
+    Ok(last_polynomial)
 }
 
-pub fn verify_i128(proof: LowDegreeProof<i128>, modulus: i128) -> Result<(), ValidationError> {
+/// Verify a proof and, on success, hand back the Merkle root it committed the codeword
+/// to. Useful for chaining: a caller that only cares about the committed root (e.g. to
+/// check it against a value received out of band) doesn't need to hold on to the proof.
+pub fn verify_bigint_and_get_root(
+    proof: LowDegreeProof<BigInt>,
+    modulus: BigInt,
+) -> Result<[u8; 32], ValidationError> {
+    let root = *proof
+        .merkle_roots
+        .first()
+        .ok_or(ValidationError::BadSizedProof)?;
+    verify_bigint(proof, modulus)?;
+    Ok(root)
+}
+
+/// `i128` counterpart of `verify_bigint_and_get_root`.
+pub fn verify_i128_and_get_root(
+    proof: LowDegreeProof<i128>,
+    modulus: i128,
+) -> Result<[u8; 32], ValidationError> {
+    let root = *proof
+        .merkle_roots
+        .first()
+        .ok_or(ValidationError::BadSizedProof)?;
+    verify_i128(proof, modulus)?;
+    Ok(root)
+}
+
+/// `u64` counterpart of `verify_bigint_and_get_root`, for proofs from `prover_u64`.
+pub fn verify_u64_and_get_root(
+    proof: LowDegreeProof<u64>,
+    modulus: u64,
+) -> Result<[u8; 32], ValidationError> {
+    let root = *proof
+        .merkle_roots
+        .first()
+        .ok_or(ValidationError::BadSizedProof)?;
+    verify_u64(proof, modulus)?;
+    Ok(root)
+}
+
+/// `u64` counterpart of `verify_i128`, for proofs from `prover_u64`/`prover_u64_coset`.
+/// Unlike `verify_i128`, every arithmetic step goes through `mod_*_u64`: `i128`
+/// multiplication overflows for moduli close to `u64::MAX`, such as the Goldilocks
+/// prime `2^64 - 2^32 + 1`, so this verifier only ever supports `folding_factor == 2`,
+/// matching `prover_u64_coset`'s restriction.
+pub fn verify_u64(proof: LowDegreeProof<u64>, modulus: u64) -> Result<(), ValidationError> {
     if proof.rounds_count != proof.ab_proofs.len() as u8
         || proof.rounds_count != proof.c_proofs.len() as u8
         || proof.rounds_count != proof.challenge_hash_preimages.len() as u8
         || proof.rounds_count + 1 != proof.merkle_roots.len() as u8
+        || proof.folding_factor != 2
     {
         return Err(ValidationError::BadSizedProof);
     }
 
-    let challenge_hashes: Vec<[u8; 32]> = proof
-        .challenge_hash_preimages
-        .iter()
-        .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
-        .collect();
-    let challenges: Vec<i128> = challenge_hashes
-        .iter()
-        .map(|x| PrimeFieldElement::from_bytes_raw(&modulus, &x[0..16]))
-        .collect();
+    if !verify_transcript_chaining(&proof) {
+        return Err(ValidationError::InconsistentTranscript);
+    }
+
+    if !verify_pow_nonce(&proof.index_picker_preimage, proof.pow_nonce, proof.pow_bits) {
+        return Err(ValidationError::InsufficientProofOfWork);
+    }
+
+    let challenges: Vec<u64> = proof.recompute_challenges(modulus);
     let mut primitive_root_of_unity = proof.primitive_root_of_unity;
+    let mut offset = proof.offset;
 
-    let field = PrimeField::new(modulus);
-    let mut c_values: Vec<i128> = vec![];
-    let mut last_a_xs: Vec<i128> = vec![];
+    let mut c_values: Vec<u64> = vec![];
+    let mut last_a_xs: Vec<u64> = vec![];
     for (i, challenge) in challenges.iter().enumerate() {
-        // Get the indices of the locations checked in this round
-        let abc_indices_option: Option<Vec<(usize, usize, usize)>> = proof.get_abc_indices(i as u8);
-        let abc_indices = abc_indices_option.unwrap();
-        let mut c_indices: Vec<usize> = vec![];
-        let mut ab_indices: Vec<usize> = vec![];
+        let abc_indices = proof.get_abc_indices(i as u8).unwrap();
+        let mut ab_indices = vec![];
+        let mut c_indices = vec![];
         for (a, b, c) in abc_indices.into_iter() {
             ab_indices.push(a);
             ab_indices.push(b);
             c_indices.push(c);
         }
+
         c_values = proof.c_proofs[i]
             .iter()
             .map(|x| x.get_value())
-            .collect::<Vec<i128>>();
+            .collect::<Vec<u64>>();
 
-        let valid_cs = MerkleTree::verify_multi_proof(
+        let cs_result = MerkleTree::verify_multi_proof_detailed(
             proof.merkle_roots[i + 1],
             &c_indices,
             &proof.c_proofs[i],
         );
-        let valid_abs =
-            MerkleTree::verify_multi_proof(proof.merkle_roots[i], &ab_indices, &proof.ab_proofs[i]);
-        if !valid_cs || !valid_abs {
-            println!(
-                "Found invalidity of indices on iteration {}: y = {}, s = {}",
-                i, valid_cs, valid_abs
-            );
-            print!("Invalid proofs:");
-            if !valid_abs {
-                println!("{:?}", &proof.c_proofs[i]);
-            }
-            if !valid_cs {
-                println!("{:?}", &proof.ab_proofs[i]);
-            }
-            return Err(ValidationError::BadMerkleProof);
+        let abs_result = MerkleTree::verify_multi_proof_detailed(
+            proof.merkle_roots[i],
+            &ab_indices,
+            &proof.ab_proofs[i],
+        );
+        if cs_result.is_err() || abs_result.is_err() {
+            let index = cs_result.err().or_else(|| abs_result.err()).unwrap();
+            return Err(ValidationError::BadMerkleProof { index });
         }
 
-        let root = PrimeFieldElement::new(primitive_root_of_unity, &field);
         for j in 0..proof.s as usize {
-            let a_index = ab_indices[2 * j] as i128;
-            let a_x = root.mod_pow_raw(a_index);
+            let c_y: u64 = proof.c_proofs[i][j].get_value();
+
+            let points: Vec<(u64, u64)> = (0..2)
+                .map(|k| {
+                    let index = ab_indices[2 * j + k] as u64;
+                    let x = mod_mul_u64(offset, mod_pow_u64(primitive_root_of_unity, index, modulus), modulus);
+                    let y: u64 = proof.ab_proofs[i][2 * j + k].get_value();
+                    (x, y)
+                })
+                .collect();
+
             if i as u8 == proof.rounds_count - 1 {
-                last_a_xs.push(a_x);
+                last_a_xs.push(points[0].0);
             }
-            let a_y: i128 = proof.ab_proofs[i][2 * j].get_value();
-            let b_index = ab_indices[2 * j + 1] as i128;
-            let b_x = root.mod_pow_raw(b_index);
-            let b_y: i128 = proof.ab_proofs[i][2 * j + 1].get_value();
-            let c_y: i128 = proof.c_proofs[i][j].get_value();
-            if !PrimeFieldPolynomial::are_colinear_raw(
-                &[(a_x, a_y), (b_x, b_y), (*challenge, c_y)],
-                modulus,
-            ) {
-                println!(
-                    "{{({},{}),({},{}),({},{})}} are not colinear",
-                    a_x, a_y, b_x, b_y, challenge, c_y
-                );
-                println!("Failed to verify colinearity!");
+
+            let mut triple = points;
+            triple.push((*challenge, c_y));
+            if degree_u64(&slow_lagrange_interpolate_u64(&triple, modulus)) > 1 {
                 return Err(ValidationError::NotColinear);
             }
         }
 
-        primitive_root_of_unity = primitive_root_of_unity * primitive_root_of_unity % modulus;
+        primitive_root_of_unity = mod_mul_u64(primitive_root_of_unity, primitive_root_of_unity, modulus);
+        offset = mod_mul_u64(offset, offset, modulus);
     }
 
-    // Base case: Verify that the values in the last merkle tree has a sufficiently low degree
-    // Verify only the c indicies
-    let c_points: Vec<(PrimeFieldElement, PrimeFieldElement)> = c_values
+    // Base case: verify that the c-values of the final round lie on a sufficiently
+    // low-degree polynomial, using for each colinearity check the final round's first
+    // opened a-value x, squared (the folding factor is always 2 here), as its x-coordinate.
+    let c_points: Vec<(u64, u64)> = c_values
         .iter()
         .zip(last_a_xs.iter())
-        .map(|(y, x)| {
-            (
-                PrimeFieldElement::new(*x, &field).mod_pow(2),
-                PrimeFieldElement::new(*y, &field),
-            )
-        })
+        .map(|(y, x)| (mod_mul_u64(*x, *x, modulus), *y))
         .collect();
-    let last_polynomial = Polynomial::slow_lagrange_interpolation(&c_points);
-    if c_values.is_empty() || last_polynomial.degree() > proof.max_degree_of_last_round as isize {
-        println!(
-            "Last y values were not of sufficiently low degree. Got: {:?}",
-            c_points
-        );
-        println!(
-            "degree of last polynomial: {}, max: {}",
-            last_polynomial.degree(),
-            proof.max_degree_of_last_round
-        );
+    if c_values.is_empty()
+        || degree_u64(&slow_lagrange_interpolate_u64(&c_points, modulus))
+            > proof.max_degree_of_last_round as isize
+    {
         return Err(ValidationError::LastIterationTooHighDegree);
     }
 
     Ok(())
 }
 
-fn fri_prover_iteration_bigint(
-    codeword: &[BigInt],
-    challenge: &BigInt,
-    modulus: &BigInt,
-    inv_two: &BigInt,
-    primitive_root_of_unity: &BigInt,
-) -> Vec<BigInt> {
-    let mut new_codeword: Vec<BigInt> = vec![bigint(0i128); codeword.len() / 2];
+/// `i128` counterpart of `verify_bigint`.
+pub fn verify_i128(proof: LowDegreeProof<i128>, modulus: i128) -> Result<(), ValidationError> {
+    if proof.rounds_count != proof.ab_proofs.len() as u8
+        || proof.rounds_count != proof.c_proofs.len() as u8
+        || proof.rounds_count != proof.challenge_hash_preimages.len() as u8
+        || proof.rounds_count + 1 != proof.merkle_roots.len() as u8
+    {
+        return Err(ValidationError::BadSizedProof);
+    }
 
-    let mut x: BigInt = BigInt::one();
-    for i in 0..new_codeword.len() {
-        let (_, x_inv, _) = PrimeFieldElementBig::eea(x.clone(), modulus.to_owned());
-        // If codeword is the evaluation of a polynomial of degree N,
-        // this is an evaluation of a polynomial of degree N/2
-        new_codeword[i] = (((1 + challenge * x_inv.clone()) * codeword[i].clone()
-            + (1 - challenge * x_inv.clone()) * codeword[i + codeword.len() / 2].clone())
-            * inv_two.to_owned()
+    if !verify_transcript_chaining(&proof) {
+        return Err(ValidationError::InconsistentTranscript);
+    }
+
+    if !verify_pow_nonce(&proof.index_picker_preimage, proof.pow_nonce, proof.pow_bits) {
+        return Err(ValidationError::InsufficientProofOfWork);
+    }
+
+    let challenges: Vec<i128> = proof.recompute_challenges(modulus);
+    let mut primitive_root_of_unity = proof.primitive_root_of_unity;
+    let mut offset = proof.offset;
+
+    let field = PrimeField::new(modulus);
+    let mut c_values: Vec<i128> = vec![];
+    let mut last_a_xs: Vec<i128> = vec![];
+    for (i, challenge) in challenges.iter().enumerate() {
+        // Get the indices of the locations checked in this round
+        let (ab_indices, c_indices): (Vec<usize>, Vec<usize>) = if proof.folding_factor == 2 {
+            let abc_indices = proof.get_abc_indices(i as u8).unwrap();
+            let mut ab_indices = vec![];
+            let mut c_indices = vec![];
+            for (a, b, c) in abc_indices.into_iter() {
+                ab_indices.push(a);
+                ab_indices.push(b);
+                c_indices.push(c);
+            }
+            (ab_indices, c_indices)
+        } else {
+            let k_ary_indices = get_k_ary_indices_internal(
+                &proof.index_picker_preimage,
+                i as u8,
+                proof.s,
+                proof.codeword_size,
+                proof.pow_nonce,
+                proof.folding_factor,
+            )
+            .unwrap();
+            let mut ab_indices = vec![];
+            let mut c_indices = vec![];
+            for (siblings, ci) in k_ary_indices.into_iter() {
+                ab_indices.extend(siblings);
+                c_indices.push(ci);
+            }
+            (ab_indices, c_indices)
+        };
+        c_values = proof.c_proofs[i]
+            .iter()
+            .map(|x| x.get_value())
+            .collect::<Vec<i128>>();
+
+        let cs_result = MerkleTree::verify_multi_proof_detailed(
+            proof.merkle_roots[i + 1],
+            &c_indices,
+            &proof.c_proofs[i],
+        );
+        let abs_result = MerkleTree::verify_multi_proof_detailed(
+            proof.merkle_roots[i],
+            &ab_indices,
+            &proof.ab_proofs[i],
+        );
+        if cs_result.is_err() || abs_result.is_err() {
+            println!(
+                "Found invalidity of indices on iteration {}: y = {}, s = {}",
+                i,
+                cs_result.is_ok(),
+                abs_result.is_ok()
+            );
+            print!("Invalid proofs:");
+            if abs_result.is_err() {
+                println!("{:?}", &proof.c_proofs[i]);
+            }
+            if cs_result.is_err() {
+                println!("{:?}", &proof.ab_proofs[i]);
+            }
+            let index = cs_result.err().or_else(|| abs_result.err()).unwrap();
+            return Err(ValidationError::BadMerkleProof { index });
+        }
+
+        let root = PrimeFieldElement::new(primitive_root_of_unity, &field);
+        let offset_elem = PrimeFieldElement::new(offset, &field);
+        let folding_factor = proof.folding_factor as usize;
+        for j in 0..proof.s as usize {
+            let c_y: i128 = proof.c_proofs[i][j].get_value();
+
+            let mut points: Vec<(PrimeFieldElement, PrimeFieldElement)> = (0..folding_factor)
+                .map(|k| {
+                    let index = ab_indices[folding_factor * j + k] as i128;
+                    let x = (offset_elem * root.mod_pow(index)).value;
+                    let y: i128 = proof.ab_proofs[i][folding_factor * j + k].get_value();
+                    (
+                        PrimeFieldElement::new(x, &field),
+                        PrimeFieldElement::new(y, &field),
+                    )
+                })
+                .collect();
+
+            if i as u8 == proof.rounds_count - 1 {
+                last_a_xs.push(points[0].0.value);
+            }
+
+            points.push((
+                PrimeFieldElement::new(*challenge, &field),
+                PrimeFieldElement::new(c_y, &field),
+            ));
+            if !Polynomial::lie_on_degree_n(&points, folding_factor - 1) {
+                println!("Failed to verify colinearity!");
+                return Err(ValidationError::NotColinear);
+            }
+        }
+
+        if proof.folding_factor == 2 {
+            primitive_root_of_unity = primitive_root_of_unity * primitive_root_of_unity % modulus;
+            offset = offset * offset % modulus;
+        } else {
+            primitive_root_of_unity = PrimeFieldElement::new(primitive_root_of_unity, &field)
+                .mod_pow(proof.folding_factor as i128)
+                .value;
+            offset = PrimeFieldElement::new(offset, &field)
+                .mod_pow(proof.folding_factor as i128)
+                .value;
+        }
+    }
+
+    // Base case: Verify that the values in the last merkle tree has a sufficiently low degree
+    // Verify only the c indicies
+    let c_points: Vec<(PrimeFieldElement, PrimeFieldElement)> = c_values
+        .iter()
+        .zip(last_a_xs.iter())
+        .map(|(y, x)| {
+            (
+                PrimeFieldElement::new(*x, &field).mod_pow(proof.folding_factor as i128),
+                PrimeFieldElement::new(*y, &field),
+            )
+        })
+        .collect();
+    let last_polynomial = Polynomial::slow_lagrange_interpolation(&c_points);
+    if c_values.is_empty() || last_polynomial.degree() > proof.max_degree_of_last_round as isize {
+        println!(
+            "Last y values were not of sufficiently low degree. Got: {:?}",
+            c_points
+        );
+        println!(
+            "degree of last polynomial: {}, max: {}",
+            last_polynomial.degree(),
+            proof.max_degree_of_last_round
+        );
+        return Err(ValidationError::LastIterationTooHighDegree);
+    }
+
+    Ok(())
+}
+
+/// Diagnostic counterpart to `verify_i128`: instead of a pass/fail against
+/// `max_degree`, interpolate the *entire* codeword via inverse NTT and report the
+/// actual degree of the resulting polynomial. Useful for explaining a
+/// `LastIterationTooHighDegree` failure, since it tells you how far off the codeword
+/// was rather than just that it failed.
+pub fn estimate_degree_i128(codeword: &[i128], modulus: i128, root: i128) -> isize {
+    let field = PrimeField::new(modulus);
+    let values: Vec<PrimeFieldElement> = codeword
+        .iter()
+        .map(|&x| PrimeFieldElement::new(x, &field))
+        .collect();
+    let root_pfe = PrimeFieldElement::new(root, &field);
+    let coefficients = intt(&values, &root_pfe);
+    Polynomial { coefficients }.degree()
+}
+
+fn fri_prover_iteration_bigint(
+    codeword: &[BigInt],
+    challenge: &BigInt,
+    modulus: &BigInt,
+    inv_two: &BigInt,
+    primitive_root_of_unity: &BigInt,
+    offset: &BigInt,
+) -> Vec<BigInt> {
+    let mut new_codeword: Vec<BigInt> = vec![bigint(0i128); codeword.len() / 2];
+
+    let mut x: BigInt = offset.clone();
+    for i in 0..new_codeword.len() {
+        let (_, x_inv, _) = PrimeFieldElementBig::eea(x.clone(), modulus.to_owned());
+        // If codeword is the evaluation of a polynomial of degree N,
+        // this is an evaluation of a polynomial of degree N/2
+        new_codeword[i] = (((1 + challenge * x_inv.clone()) * codeword[i].clone()
+            + (1 - challenge * x_inv.clone()) * codeword[i + codeword.len() / 2].clone())
+            * inv_two.to_owned()
             % modulus.to_owned()
             + modulus.to_owned())
             % modulus.to_owned();
@@ -537,60 +1457,393 @@ fn fri_prover_iteration_i128(
     modulus: &i128,
     inv_two: &i128,
     primitive_root_of_unity: &i128,
+    offset: &i128,
 ) -> Vec<i128> {
     let mut new_codeword: Vec<i128> = vec![0i128; codeword.len() / 2];
 
-    let mut x = 1i128;
+    let mut x = *offset;
     for i in 0..new_codeword.len() {
         let (_, x_inv, _) = PrimeFieldElement::eea(x, *modulus);
+        // Each multiplication below is reduced mod `modulus` as soon as it's formed,
+        // rather than only at the very end: `challenge * x_inv` alone can be almost
+        // `modulus^2`, and multiplying that again by a codeword value (also close to
+        // `modulus`) would overflow `i128` well before reaching the final `% modulus`,
+        // for moduli near `2^62`.
+        let challenge_x_inv = (challenge * x_inv).rem_euclid(*modulus);
+        let term_a = ((1 + challenge_x_inv) * codeword[i]).rem_euclid(*modulus);
+        let term_b =
+            ((1 - challenge_x_inv) * codeword[i + codeword.len() / 2]).rem_euclid(*modulus);
         // If codeword is the evaluation of a polynomial of degree N,
         // this is an evaluation of a polynomial of degree N/2
-        new_codeword[i] = (((1 + challenge * x_inv) * codeword[i]
-            + (1 - challenge * x_inv) * codeword[i + codeword.len() / 2])
-            * *inv_two
-            % *modulus
-            + *modulus)
-            % *modulus;
+        new_codeword[i] = ((term_a + term_b) * *inv_two).rem_euclid(*modulus);
         x = x * *primitive_root_of_unity % modulus;
     }
     new_codeword
 }
 
+/// `a * b mod modulus` for moduli up to `u64::MAX`. `a` and `b` can each be as large as
+/// `u64::MAX`, so their product can overflow `u64` (and even `i128`, for moduli close to
+/// `u64::MAX` such as the Goldilocks prime); `u128` is wide enough to hold it exactly.
+fn mod_mul_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// `a + b mod modulus`, via a `u128` intermediate to avoid `u64` overflow when both
+/// inputs are close to `modulus`.
+fn mod_add_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + b as u128) % modulus as u128) as u64
+}
+
+/// `a - b mod modulus`, via a `u128` intermediate so the subtraction never underflows
+/// `u64` regardless of the relative size of `a` and `b`.
+fn mod_sub_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + modulus as u128 - b as u128) % modulus as u128) as u64
+}
+
+fn mod_pow_u64(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut acc: u64 = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            acc = mod_mul_u64(acc, base, modulus);
+        }
+        base = mod_mul_u64(base, base, modulus);
+        exponent >>= 1;
+    }
+    acc
+}
+
+/// Modular inverse of `a` mod `modulus`, computed via `PrimeFieldElement::eea`
+/// instantiated at `i128`: `eea`'s extended-Euclidean bookkeeping subtracts
+/// intermediate Bezout coefficients from one another, which underflows if run
+/// directly on `u64`, but every value involved fits comfortably in `i128` since
+/// `modulus` is at most `u64::MAX`.
+fn mod_inverse_u64(a: u64, modulus: u64) -> u64 {
+    let (_, _, inverse) = PrimeFieldElement::eea(modulus as i128, a as i128);
+    ((inverse % modulus as i128 + modulus as i128) % modulus as i128) as u64
+}
+
+/// Read a big-endian integer out of `buf` (as `PrimeFieldElement::from_bytes_raw` does
+/// for `i128`) and reduce it mod `modulus`. `buf` is expected to be 16 bytes (a blake3
+/// hash prefix), which fits exactly in a `u128` accumulator.
+fn from_bytes_raw_u64(modulus: u64, buf: &[u8]) -> u64 {
+    let mut acc: u128 = 0;
+    for &byte in buf {
+        acc = (acc << 8) | byte as u128;
+    }
+    (acc % modulus as u128) as u64
+}
+
+/// Interpolate the polynomial of degree `< points.len()` through `points` (which must
+/// have distinct x-coordinates) via Lagrange interpolation over `mod_*_u64`, returning
+/// its coefficients, lowest-order term first. `O(points.len()^2)`, matching the cost of
+/// `Polynomial::slow_lagrange_interpolation`, which this substitutes for since
+/// `PrimeFieldElement`'s `i128` multiplication overflows for moduli close to
+/// `u64::MAX` such as the Goldilocks prime.
+fn slow_lagrange_interpolate_u64(points: &[(u64, u64)], modulus: u64) -> Vec<u64> {
+    let n = points.len();
+    let mut coefficients = vec![0u64; n];
+    for i in 0..n {
+        let (xi, yi) = points[i];
+        let mut numerator = vec![1u64];
+        let mut denominator = 1u64;
+        for (k, &(xk, _)) in points.iter().enumerate() {
+            if k == i {
+                continue;
+            }
+            denominator = mod_mul_u64(denominator, mod_sub_u64(xi, xk, modulus), modulus);
+
+            let mut shifted = vec![0u64; numerator.len() + 1];
+            for (d, &coeff) in numerator.iter().enumerate() {
+                shifted[d + 1] = mod_add_u64(shifted[d + 1], coeff, modulus);
+                shifted[d] = mod_sub_u64(shifted[d], mod_mul_u64(coeff, xk, modulus), modulus);
+            }
+            numerator = shifted;
+        }
+
+        let scale = mod_mul_u64(yi, mod_inverse_u64(denominator, modulus), modulus);
+        for (d, &coeff) in numerator.iter().enumerate() {
+            coefficients[d] = mod_add_u64(coefficients[d], mod_mul_u64(coeff, scale, modulus), modulus);
+        }
+    }
+    coefficients
+}
+
+/// Degree of the polynomial represented by `coefficients` (lowest-order term first),
+/// i.e. the index of the highest non-zero coefficient, or `-1` for the zero polynomial.
+fn degree_u64(coefficients: &[u64]) -> isize {
+    for i in (0..coefficients.len()).rev() {
+        if coefficients[i] != 0 {
+            return i as isize;
+        }
+    }
+    -1
+}
+
+fn fri_prover_iteration_u64(
+    codeword: &[u64],
+    challenge: &u64,
+    modulus: &u64,
+    inv_two: &u64,
+    primitive_root_of_unity: &u64,
+    offset: &u64,
+) -> Vec<u64> {
+    let mut new_codeword: Vec<u64> = vec![0u64; codeword.len() / 2];
+
+    let mut x = *offset;
+    for i in 0..new_codeword.len() {
+        let x_inv = mod_inverse_u64(x, *modulus);
+        let challenge_x_inv = mod_mul_u64(*challenge, x_inv, *modulus);
+
+        // If codeword is the evaluation of a polynomial of degree N,
+        // this is an evaluation of a polynomial of degree N/2
+        let even_term = mod_mul_u64(
+            mod_add_u64(1, challenge_x_inv, *modulus),
+            codeword[i],
+            *modulus,
+        );
+        let odd_term = mod_mul_u64(
+            mod_sub_u64(1, challenge_x_inv, *modulus),
+            codeword[i + codeword.len() / 2],
+            *modulus,
+        );
+        new_codeword[i] = mod_mul_u64(mod_add_u64(even_term, odd_term, *modulus), *inv_two, *modulus);
+        x = mod_mul_u64(x, *primitive_root_of_unity, *modulus);
+    }
+    new_codeword
+}
+
+/// Generalization of `fri_prover_iteration_bigint` to folding factors greater than
+/// two: for each output index, interpolate the degree-`< folding_factor` polynomial
+/// through the `folding_factor` sibling input points and evaluate it at `challenge`.
+/// For `folding_factor == 2` this computes the same values as
+/// `fri_prover_iteration_bigint`, just less efficiently, so callers keep using the
+/// closed-form version in that case.
+fn fri_fold_bigint_general(
+    codeword: &[BigInt],
+    challenge: &BigInt,
+    modulus: &BigInt,
+    primitive_root_of_unity: &BigInt,
+    offset: &BigInt,
+    folding_factor: usize,
+) -> Vec<BigInt> {
+    let field = PrimeFieldBig::new(modulus.clone());
+    let new_size = codeword.len() / folding_factor;
+    let challenge_elem = PrimeFieldElementBig::new(challenge.clone(), &field);
+    let root = PrimeFieldElementBig::new(primitive_root_of_unity.clone(), &field);
+    let mut x = PrimeFieldElementBig::new(offset.clone(), &field);
+    let mut new_codeword: Vec<BigInt> = Vec::with_capacity(new_size);
+    for i in 0..new_size {
+        let points: Vec<(PrimeFieldElementBig, PrimeFieldElementBig)> = (0..folding_factor)
+            .map(|j| {
+                let sibling_x = x.clone() * root.mod_pow(bigint((j * new_size) as i128));
+                let sibling_y = PrimeFieldElementBig::new(codeword[i + j * new_size].clone(), &field);
+                (sibling_x, sibling_y)
+            })
+            .collect();
+        let interpolant = Polynomial::slow_lagrange_interpolation(&points);
+        new_codeword.push(interpolant.evaluate(&challenge_elem).value);
+        x = x * root.clone();
+    }
+    new_codeword
+}
+
+/// `i128` counterpart of `fri_fold_bigint_general`.
+fn fri_fold_i128_general(
+    codeword: &[i128],
+    challenge: &i128,
+    modulus: &i128,
+    primitive_root_of_unity: &i128,
+    offset: &i128,
+    folding_factor: usize,
+) -> Vec<i128> {
+    let field = PrimeField::new(*modulus);
+    let new_size = codeword.len() / folding_factor;
+    let challenge_elem = PrimeFieldElement::new(*challenge, &field);
+    let root = PrimeFieldElement::new(*primitive_root_of_unity, &field);
+    let mut x = PrimeFieldElement::new(*offset, &field);
+    let mut new_codeword: Vec<i128> = Vec::with_capacity(new_size);
+    for i in 0..new_size {
+        let points: Vec<(PrimeFieldElement, PrimeFieldElement)> = (0..folding_factor)
+            .map(|j| {
+                (
+                    x * root.mod_pow((j * new_size) as i128),
+                    PrimeFieldElement::new(codeword[i + j * new_size], &field),
+                )
+            })
+            .collect();
+        let interpolant = Polynomial::slow_lagrange_interpolation(&points);
+        new_codeword.push(interpolant.evaluate(&challenge_elem).value);
+        x = x * root;
+    }
+    new_codeword
+}
+
+/// Generalization of `LowDegreeProof::get_abc_indices_internal` to folding factors
+/// greater than two: for each of `num_locations` output indices in the *folded*
+/// codeword, pick the `folding_factor` sibling indices in the round's input codeword
+/// that fold together into it. Kept separate from `get_abc_indices_internal` (rather
+/// than generalizing its triple-based return type) so the public `get_abc_indices`/
+/// `get_ab_indices` API, which several callers outside this module rely on for the
+/// default folding factor of two, doesn't have to change shape.
+fn get_k_ary_indices_internal(
+    index_picker_preimage: &[u8],
+    round: u8,
+    num_locations: u32,
+    full_codeword_size: u32,
+    pow_nonce: u64,
+    folding_factor: u8,
+) -> Option<Vec<(Vec<usize>, usize)>> {
+    let log2_folding_factor = folding_factor.trailing_zeros();
+    let folded_size = full_codeword_size as usize >> (log2_folding_factor * (round as u32 + 1));
+
+    if num_locations > 0xFF {
+        panic!("Max num_locations is 256. Got: {}", num_locations);
+    }
+    if folded_size < num_locations as usize {
+        return None;
+    }
+
+    let mut hash_preimage_clone = index_picker_preimage.to_vec();
+    hash_preimage_clone.extend_from_slice(&pow_nonce.to_le_bytes());
+    hash_preimage_clone.push(round);
+    let siblings_of = |index: usize| -> (Vec<usize>, usize) {
+        (
+            (0..folding_factor as usize)
+                .map(|j| index + j * folded_size)
+                .collect(),
+            index,
+        )
+    };
+
+    let mut k_ary_indices: Vec<(Vec<usize>, usize)> = vec![];
+    if num_locations > folded_size as u32 / 2 {
+        let mut remaining: Vec<usize> = (0..folded_size).collect();
+        for i in 0..num_locations {
+            let mut index_picker_prehash_temp = hash_preimage_clone.clone();
+            index_picker_prehash_temp.push((i % 256) as u8);
+            let hash = blake3_digest(index_picker_prehash_temp.as_slice());
+            let index_index = get_index_from_bytes(&hash, remaining.len());
+            let index = remaining.remove(index_index);
+            k_ary_indices.push(siblings_of(index));
+        }
+    } else {
+        let mut picked: HashSet<usize> = HashSet::<usize>::new();
+        let mut counter: u8 = 0;
+        while k_ary_indices.len() < num_locations as usize {
+            let mut index_picker_prehash_temp = hash_preimage_clone.clone();
+            index_picker_prehash_temp.push(counter);
+            let hash = blake3_digest(index_picker_prehash_temp.as_slice());
+            let index = get_index_from_bytes(&hash, folded_size);
+            if !picked.contains(&index) {
+                k_ary_indices.push(siblings_of(index));
+                picked.insert(index);
+            }
+            counter += 1;
+        }
+    }
+
+    Some(k_ary_indices)
+}
+
 fn prover_shared<T: Clone + Debug + Serialize + PartialEq>(
     max_degree: u32,
-    output: &mut Vec<u8>,
     codeword: &[T],
     s: usize,
     primitive_root_of_unity: T,
-) -> Result<(usize, Vec<MerkleTree<T>>, u32), ProveError> {
+    offset: T,
+    pow_bits: u8,
+    folding_factor: u8,
+    precomputed_first_tree: Option<MerkleTree<T>>,
+) -> Result<(usize, Vec<MerkleTree<T>>, u32, Vec<u8>), ProveError> {
     let max_degree_plus_one: u32 = max_degree + 1;
     if max_degree_plus_one & (max_degree_plus_one - 1) != 0 {
-        return Err(ProveError::BadMaxDegreeValue);
+        return Err(ProveError::BadMaxDegreeValue { max_degree });
+    }
+    // `max_degree == 0` would make `get_rounds_count` compute zero rounds
+    // (`log_2_ceil(1) == 0`), which is indistinguishable from `NonPostiveRoundCount`
+    // but has a more specific cause, so it gets called out separately here.
+    if max_degree == 0 {
+        return Err(ProveError::MaxDegreeTooSmall);
+    }
+    if !folding_factor.is_power_of_two() || folding_factor < 2 {
+        return Err(ProveError::BadFoldingFactor);
+    }
+    let codeword_len = codeword.len() as u32;
+    if !codeword_len.is_power_of_two() || codeword_len < 2 * max_degree_plus_one {
+        return Err(ProveError::CodewordSizeMismatch);
     }
 
-    output.append(&mut bincode::serialize(&(codeword.len() as u32)).unwrap());
-    output.append(&mut bincode::serialize(&(max_degree as u32)).unwrap());
-    output.append(&mut bincode::serialize(&(s as u32)).unwrap());
+    // The proof body is built up in a local buffer rather than directly in the
+    // caller's `output`, so its total length is known before anything is written to
+    // `output`. This lets `prover_bigint_coset`/`prover_i128_coset` prefix it with a
+    // length header without ever rewriting bytes that have already been hashed into
+    // a Fiat-Shamir challenge.
+    let mut body: Vec<u8> = vec![];
+    body.append(&mut bincode::serialize(&(codeword.len() as u32)).unwrap());
+    body.append(&mut bincode::serialize(&(max_degree as u32)).unwrap());
+    body.append(&mut bincode::serialize(&(s as u32)).unwrap());
 
     // First append length of primitive root, then actual value
     let root_serialization: Vec<u8> = bincode::serialize(&(primitive_root_of_unity)).unwrap();
     let root_serialization_length: u16 = root_serialization.len() as u16;
-    output.append(&mut bincode::serialize(&root_serialization_length).unwrap());
-    output.append(&mut bincode::serialize(&(primitive_root_of_unity)).unwrap());
-
-    let mt: MerkleTree<T> = MerkleTree::from_vec(codeword);
+    body.append(&mut bincode::serialize(&root_serialization_length).unwrap());
+    body.append(&mut bincode::serialize(&(primitive_root_of_unity)).unwrap());
+
+    // Same scheme for the coset offset (1 for plain subgroup FRI)
+    let offset_serialization: Vec<u8> = bincode::serialize(&offset).unwrap();
+    let offset_serialization_length: u16 = offset_serialization.len() as u16;
+    body.append(&mut bincode::serialize(&offset_serialization_length).unwrap());
+    body.append(&mut bincode::serialize(&offset).unwrap());
+
+    // Grinding difficulty. Read back before any Fiat-Shamir hash is derived, so
+    // it becomes part of every challenge/index-picker preimage that follows.
+    body.append(&mut bincode::serialize(&pow_bits).unwrap());
+    body.append(&mut bincode::serialize(&folding_factor).unwrap());
+
+    // The caller may already have committed to `codeword` elsewhere (e.g. a STARK's
+    // trace codeword before FRI starts) -- reuse that tree for round 0 instead of
+    // hashing the codeword a second time, after checking in debug builds that it's
+    // really a commitment to this codeword.
+    let mt: MerkleTree<T> = match precomputed_first_tree {
+        Some(tree) => {
+            debug_assert_eq!(
+                tree.get_root(),
+                MerkleTree::from_vec(codeword).get_root(),
+                "Precomputed first Merkle tree's root does not match the codeword"
+            );
+            tree
+        }
+        None => MerkleTree::from_vec(codeword),
+    };
     let mts: Vec<MerkleTree<T>> = vec![mt];
 
-    output.append(&mut mts[0].get_root().to_vec());
+    body.append(&mut mts[0].get_root().to_vec());
     let (rounds_count, max_degree_of_last_round) =
-        get_rounds_count(codeword.len() as u32, max_degree, s as u32);
+        get_rounds_count(codeword.len() as u32, max_degree, s as u32, folding_factor).ok_or(
+            ProveError::NonPostiveRoundCount {
+                codeword_size: codeword_len,
+                max_degree,
+                s: s as u32,
+            },
+        )?;
 
-    // Require that the prover runs at least *one* round of code word size halving
+    // Require that the prover runs at least *one* round of codeword size reduction
     if rounds_count < 1 {
-        return Err(ProveError::NonPostiveRoundCount);
+        return Err(ProveError::NonPostiveRoundCount {
+            codeword_size: codeword_len,
+            max_degree,
+            s: s as u32,
+        });
     }
 
-    Ok((rounds_count as usize, mts, max_degree_of_last_round as u32))
+    Ok((
+        rounds_count as usize,
+        mts,
+        max_degree_of_last_round as u32,
+        body,
+    ))
 }
 
 pub fn prover_bigint(
@@ -601,13 +1854,133 @@ pub fn prover_bigint(
     output: &mut Vec<u8>,
     primitive_root_of_unity: BigInt,
 ) -> Result<LowDegreeProof<BigInt>, ProveError> {
-    let (rounds_count, mut mts, max_degree_of_last_round): (usize, Vec<MerkleTree<BigInt>>, u32) =
-        prover_shared(
+    prover_bigint_coset(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        bigint(1),
+        0,
+        2,
+    )
+}
+
+/// Like `prover_bigint`, but reuses an externally built Merkle tree for the
+/// codeword's round-0 commitment instead of rebuilding it. See
+/// `prover_bigint_coset_with_commitment`.
+pub fn prover_bigint_with_commitment(
+    codeword: &[BigInt],
+    modulus: BigInt,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: BigInt,
+    first_tree: MerkleTree<BigInt>,
+) -> Result<LowDegreeProof<BigInt>, ProveError> {
+    prover_bigint_coset_with_commitment(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        bigint(1),
+        0,
+        2,
+        first_tree,
+    )
+}
+
+/// Like `prover_bigint`, but for a codeword evaluated over the coset `offset * <primitive_root_of_unity>`
+/// rather than the subgroup generated by `primitive_root_of_unity` itself, with a
+/// configurable proof-of-work grinding difficulty `pow_bits` (`0` disables grinding),
+/// and a configurable `folding_factor` (a power of two, `2` is plain halving).
+pub fn prover_bigint_coset(
+    codeword: &[BigInt],
+    modulus: BigInt,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: BigInt,
+    offset: BigInt,
+    pow_bits: u8,
+    folding_factor: u8,
+) -> Result<LowDegreeProof<BigInt>, ProveError> {
+    prover_bigint_coset_shared(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        offset,
+        pow_bits,
+        folding_factor,
+        None,
+    )
+}
+
+/// Like `prover_bigint_coset`, but reuses an externally built Merkle tree for the
+/// codeword's round-0 commitment instead of rebuilding it -- useful when the
+/// codeword was already committed to elsewhere in the pipeline (e.g. a STARK's
+/// trace codeword before FRI starts). In debug builds, asserts that `first_tree`'s
+/// root matches `codeword`.
+#[allow(clippy::too_many_arguments)]
+pub fn prover_bigint_coset_with_commitment(
+    codeword: &[BigInt],
+    modulus: BigInt,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: BigInt,
+    offset: BigInt,
+    pow_bits: u8,
+    folding_factor: u8,
+    first_tree: MerkleTree<BigInt>,
+) -> Result<LowDegreeProof<BigInt>, ProveError> {
+    prover_bigint_coset_shared(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        offset,
+        pow_bits,
+        folding_factor,
+        Some(first_tree),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prover_bigint_coset_shared(
+    codeword: &[BigInt],
+    modulus: BigInt,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: BigInt,
+    offset: BigInt,
+    pow_bits: u8,
+    folding_factor: u8,
+    precomputed_first_tree: Option<MerkleTree<BigInt>>,
+) -> Result<LowDegreeProof<BigInt>, ProveError> {
+    let (rounds_count, mut mts, max_degree_of_last_round, mut body): (
+        usize,
+        Vec<MerkleTree<BigInt>>,
+        u32,
+        Vec<u8>,
+    ) = prover_shared(
             max_degree,
-            output,
             codeword,
             s,
             primitive_root_of_unity.clone(),
+            offset.clone(),
+            pow_bits,
+            folding_factor,
+            precomputed_first_tree,
         )?;
     let mut mut_codeword: Vec<BigInt> = codeword.to_vec();
 
@@ -619,38 +1992,65 @@ pub fn prover_bigint(
     let (_, _, inv2_temp) = PrimeFieldElementBig::eea(modulus.clone(), bigint(2));
     let inv2 = (inv2_temp + modulus.clone()) % modulus.clone();
     let mut primitive_root_of_unity_temp = primitive_root_of_unity.clone();
+    let mut offset_temp = offset.clone();
     let mut challenge_hash_preimages: Vec<Vec<u8>> = vec![];
     for _ in 0..rounds_count {
         // get challenge
-        challenge_hash_preimages.push(output.clone());
-        let hash = *blake3::hash(output.as_slice()).as_bytes();
+        challenge_hash_preimages.push(body.clone());
+        let hash = *blake3::hash(body.as_slice()).as_bytes();
         let challenge: BigInt = PrimeFieldElementBig::from_bytes_raw(&modulus, &hash[0..16]);
 
-        // run fri iteration reducing the degree of the polynomial by one half.
-        // This is achieved by realizing that
-        // P(x) + P(-x) = 2*P_e(x^2) and P(x) - P(-x) = 2*P_o(x^2) where P_e, P_o both
-        // have half the degree of P.
-        mut_codeword = fri_prover_iteration_bigint(
-            &mut_codeword.clone(),
-            &challenge,
-            &modulus,
-            &inv2,
-            &primitive_root_of_unity_temp,
-        );
+        // Fold the codeword down by `folding_factor`. For the default `folding_factor ==
+        // 2` this is the closed-form butterfly: P(x) + P(-x) = 2*P_e(x^2) and
+        // P(x) - P(-x) = 2*P_o(x^2) where P_e, P_o both have 1/2 the degree of P. For
+        // larger folding factors we fall back to interpolating the fold directly.
+        mut_codeword = if folding_factor == 2 {
+            fri_prover_iteration_bigint(
+                &mut_codeword.clone(),
+                &challenge,
+                &modulus,
+                &inv2,
+                &primitive_root_of_unity_temp,
+                &offset_temp,
+            )
+        } else {
+            fri_fold_bigint_general(
+                &mut_codeword.clone(),
+                &challenge,
+                &modulus,
+                &primitive_root_of_unity_temp,
+                &offset_temp,
+                folding_factor as usize,
+            )
+        };
 
-        // Construct Merkle Tree from the new codeword of degree `max_degree / 2`
+        // Construct Merkle Tree from the new, `folding_factor`-times smaller codeword
         let mt = MerkleTree::from_vec(&mut_codeword);
 
         // append root to proof
-        output.append(&mut mt.get_root().to_vec());
+        body.append(&mut mt.get_root().to_vec());
 
         // collect into memory
         mts.push(mt);
 
-        // num_rounds += 1;
-        primitive_root_of_unity_temp = primitive_root_of_unity_temp.clone()
-            * primitive_root_of_unity_temp.clone()
-            % modulus.clone();
+        // num_rounds += 1. The domain shrinks by `folding_factor` each round, so its
+        // generator and offset need raising to that same power (squaring, for the
+        // default folding factor of two).
+        if folding_factor == 2 {
+            primitive_root_of_unity_temp = primitive_root_of_unity_temp.clone()
+                * primitive_root_of_unity_temp.clone()
+                % modulus.clone();
+            offset_temp = offset_temp.clone() * offset_temp.clone() % modulus.clone();
+        } else {
+            let field = PrimeFieldBig::new(modulus.clone());
+            primitive_root_of_unity_temp =
+                PrimeFieldElementBig::new(primitive_root_of_unity_temp, &field)
+                    .mod_pow(bigint(folding_factor as i128))
+                    .value;
+            offset_temp = PrimeFieldElementBig::new(offset_temp, &field)
+                .mod_pow(bigint(folding_factor as i128))
+                .value;
+        }
     }
 
     // query phase
@@ -662,39 +2062,63 @@ pub fn prover_bigint(
     // -- query P2 in s1 -> alpha1
     // -- query P2 in s2 -> alpha2
     // -- check collinearity (s0, alpha0), (s1, alpha1), (y, beta) <-- we don't care about thi right nw>
-    let index_picker_preimage = output.clone();
+    let index_picker_preimage = body.clone();
+    let pow_nonce = grind_pow_nonce(&index_picker_preimage, pow_bits);
+    body.append(&mut bincode::serialize(&pow_nonce).unwrap());
     primitive_root_of_unity_temp = primitive_root_of_unity.clone();
     for i in 0usize..rounds_count {
         // Get the indices of the locations checked in this round
-        let abc_indices_option: Option<Vec<(usize, usize, usize)>> =
-            LowDegreeProof::<BigInt>::get_abc_indices_internal(
+        let (ab_indices, c_indices): (Vec<usize>, Vec<usize>) = if folding_factor == 2 {
+            let abc_indices = LowDegreeProof::<BigInt>::get_abc_indices_internal(
                 &index_picker_preimage,
                 i as u8,
                 s as u32,
                 codeword.len() as u32,
-            );
-        let abc_indices = abc_indices_option.unwrap();
-        let mut c_indices: Vec<usize> = vec![];
-        let mut ab_indices: Vec<usize> = vec![];
-        for (a, b, c) in abc_indices.into_iter() {
-            ab_indices.push(a);
-            ab_indices.push(b);
-            c_indices.push(c);
-        }
+                pow_nonce,
+            )
+            .unwrap();
+            let mut ab = vec![];
+            let mut c = vec![];
+            for (a, b, ci) in abc_indices.into_iter() {
+                ab.push(a);
+                ab.push(b);
+                c.push(ci);
+            }
+            (ab, c)
+        } else {
+            let k_ary_indices = get_k_ary_indices_internal(
+                &index_picker_preimage,
+                i as u8,
+                s as u32,
+                codeword.len() as u32,
+                pow_nonce,
+                folding_factor,
+            )
+            .unwrap();
+            let mut ab = vec![];
+            let mut c = vec![];
+            for (siblings, ci) in k_ary_indices.into_iter() {
+                ab.extend(siblings);
+                c.push(ci);
+            }
+            (ab, c)
+        };
 
+        // `mts[i]` holds this round's codeword, `mts[i + 1]` the folded codeword
+        // produced by this round's fri iteration; the c-values live in the latter.
         let authentication_paths_c: Vec<PartialAuthenticationPath<BigInt>> =
             mts[i + 1].get_multi_proof(&c_indices);
         let authentication_paths_ab: Vec<PartialAuthenticationPath<BigInt>> =
             mts[i].get_multi_proof(&ab_indices);
 
-        // serialize proofs and store in output
+        // serialize proofs and store in the body
         let mut c_paths_encoded = bincode::serialize(&authentication_paths_c.clone()).unwrap();
-        output.append(&mut bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
-        output.append(&mut c_paths_encoded);
+        body.append(&mut bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut c_paths_encoded);
 
         let mut ab_paths_encoded = bincode::serialize(&authentication_paths_ab.clone()).unwrap();
-        output.append(&mut bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
-        output.append(&mut ab_paths_encoded);
+        body.append(&mut bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut ab_paths_encoded);
 
         primitive_root_of_unity_temp = primitive_root_of_unity_temp.clone()
             * primitive_root_of_unity_temp.clone()
@@ -705,6 +2129,13 @@ pub fn prover_bigint(
         ab_proofs.push(authentication_paths_ab);
     }
 
+    // Prefix the finished body with a format version and its length, now that the
+    // length is known and nothing referencing the body's bytes (e.g. the
+    // Fiat-Shamir preimages above) needs to be hashed again.
+    output.push(PROOF_FORMAT_VERSION);
+    output.append(&mut bincode::serialize(&(body.len() as u32)).unwrap());
+    output.append(&mut body);
+
     Ok(LowDegreeProof::<BigInt> {
         rounds_count: rounds_count as u8,
         challenge_hash_preimages,
@@ -714,6 +2145,10 @@ pub fn prover_bigint(
         s: s as u32,
         merkle_roots: mts.iter().map(|x| x.get_root()).collect::<Vec<[u8; 32]>>(),
         codeword_size: codeword.len() as u32,
+        offset,
+        pow_bits,
+        pow_nonce,
+        folding_factor,
         primitive_root_of_unity,
         max_degree,
         max_degree_of_last_round,
@@ -721,7 +2156,9 @@ pub fn prover_bigint(
 }
 
 // TODO: We want this implemented for prime field elements, and preferably for
-// any finite field/extension field.
+// any finite field/extension field. `extension_field::ExtensionFieldElement` now
+// implements `FieldElement`, so it's a candidate for that generalization, but the
+// prover below isn't generic over it yet.
 // Prove that codeword elements come from the evaluation of a polynomial of
 // `degree < codeword.len() / expansion_factor`
 pub fn prover_i128(
@@ -732,104 +2169,472 @@ pub fn prover_i128(
     output: &mut Vec<u8>,
     primitive_root_of_unity: i128,
 ) -> Result<LowDegreeProof<i128>, ProveError> {
-    let (rounds_count, mut mts, max_degree_of_last_round): (usize, Vec<MerkleTree<i128>>, u32) =
-        prover_shared(max_degree, output, codeword, s, primitive_root_of_unity)?;
+    prover_i128_coset(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        1,
+        0,
+        2,
+    )
+}
+
+/// Like `prover_i128`, but for a codeword evaluated over the coset `offset * <primitive_root_of_unity>`
+/// rather than the subgroup generated by `primitive_root_of_unity` itself, with a
+/// configurable proof-of-work grinding difficulty `pow_bits` (`0` disables grinding),
+/// and a configurable `folding_factor` (a power of two, `2` is plain halving).
+pub fn prover_i128_coset(
+    codeword: &[i128],
+    modulus: i128,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: i128,
+    offset: i128,
+    pow_bits: u8,
+    folding_factor: u8,
+) -> Result<LowDegreeProof<i128>, ProveError> {
+    let (rounds_count, mut mts, max_degree_of_last_round, mut body): (
+        usize,
+        Vec<MerkleTree<i128>>,
+        u32,
+        Vec<u8>,
+    ) = prover_shared(
+            max_degree,
+            codeword,
+            s,
+            primitive_root_of_unity,
+            offset,
+            pow_bits,
+            folding_factor,
+            None,
+        )?;
+
+    // Arrays for return values
+    let mut c_proofs: Vec<Vec<PartialAuthenticationPath<i128>>> = vec![];
+    let mut ab_proofs: Vec<Vec<PartialAuthenticationPath<i128>>> = vec![];
+
+    let mut mut_codeword: Vec<i128> = codeword.to_vec();
+
+    // commit phase
+    let (_, _, inv2_temp) = PrimeFieldElement::eea(modulus, 2);
+    let inv2 = (inv2_temp + modulus) % modulus;
+    let mut primitive_root_of_unity_temp = primitive_root_of_unity;
+    let mut offset_temp = offset;
+    let mut challenge_hash_preimages: Vec<Vec<u8>> = vec![];
+    for _ in 0..rounds_count {
+        // get challenge
+        challenge_hash_preimages.push(body.clone());
+        let hash = *blake3::hash(body.as_slice()).as_bytes();
+        let challenge: i128 = PrimeFieldElement::from_bytes_raw(&modulus, &hash[0..16]);
+
+        // Fold the codeword down by `folding_factor`. For the default `folding_factor ==
+        // 2` this is the closed-form butterfly: P(x) + P(-x) = 2*P_e(x^2) and
+        // P(x) - P(-x) = 2*P_o(x^2) where P_e, P_o both have 1/2 the degree of P. For
+        // larger folding factors we fall back to interpolating the fold directly.
+        mut_codeword = if folding_factor == 2 {
+            fri_prover_iteration_i128(
+                &mut_codeword.clone(),
+                &challenge,
+                &modulus,
+                &inv2,
+                &primitive_root_of_unity_temp,
+                &offset_temp,
+            )
+        } else {
+            fri_fold_i128_general(
+                &mut_codeword.clone(),
+                &challenge,
+                &modulus,
+                &primitive_root_of_unity_temp,
+                &offset_temp,
+                folding_factor as usize,
+            )
+        };
+
+        // Construct Merkle Tree from the new, `folding_factor`-times smaller codeword
+        let mt = MerkleTree::from_vec(&mut_codeword);
+
+        // append root to proof
+        body.append(&mut mt.get_root().to_vec());
+
+        // collect into memory
+        mts.push(mt);
+
+        // num_rounds += 1. The domain shrinks by `folding_factor` each round, so its
+        // generator and offset need raising to that same power (squaring, for the
+        // default folding factor of two).
+        if folding_factor == 2 {
+            primitive_root_of_unity_temp =
+                primitive_root_of_unity_temp * primitive_root_of_unity_temp % modulus;
+            offset_temp = offset_temp * offset_temp % modulus;
+        } else {
+            let field = PrimeField::new(modulus);
+            primitive_root_of_unity_temp =
+                PrimeFieldElement::new(primitive_root_of_unity_temp, &field)
+                    .mod_pow(folding_factor as i128)
+                    .value;
+            offset_temp = PrimeFieldElement::new(offset_temp, &field)
+                .mod_pow(folding_factor as i128)
+                .value;
+        }
+    }
+
+    // query phase
+    // for all subsequent pairs of merkle trees:
+    // - do s times:
+    // -- sample random point y in L2
+    // -- compute square roots s1 s2
+    // -- query P1 in y -> beta
+    // -- query P2 in s1 -> alpha1
+    // -- query P2 in s2 -> alpha2
+    // -- check collinearity (s0, alpha0), (s1, alpha1), (y, beta) <-- we don't care about thi right nw>
+    let index_picker_preimage = body.clone();
+    let pow_nonce = grind_pow_nonce(&index_picker_preimage, pow_bits);
+    body.append(&mut bincode::serialize(&pow_nonce).unwrap());
+    primitive_root_of_unity_temp = primitive_root_of_unity;
+    for i in 0usize..rounds_count {
+        // Get the indices of the locations checked in this round
+        let (ab_indices, c_indices): (Vec<usize>, Vec<usize>) = if folding_factor == 2 {
+            let abc_indices = LowDegreeProof::<i128>::get_abc_indices_internal(
+                &index_picker_preimage,
+                i as u8,
+                s as u32,
+                codeword.len() as u32,
+                pow_nonce,
+            )
+            .unwrap();
+            let mut ab = vec![];
+            let mut c = vec![];
+            for (a, b, ci) in abc_indices.into_iter() {
+                ab.push(a);
+                ab.push(b);
+                c.push(ci);
+            }
+            (ab, c)
+        } else {
+            let k_ary_indices = get_k_ary_indices_internal(
+                &index_picker_preimage,
+                i as u8,
+                s as u32,
+                codeword.len() as u32,
+                pow_nonce,
+                folding_factor,
+            )
+            .unwrap();
+            let mut ab = vec![];
+            let mut c = vec![];
+            for (siblings, ci) in k_ary_indices.into_iter() {
+                ab.extend(siblings);
+                c.push(ci);
+            }
+            (ab, c)
+        };
+
+        // `mts[i]` holds this round's codeword, `mts[i + 1]` the folded codeword
+        // produced by this round's fri iteration; the c-values live in the latter.
+        let authentication_paths_c: Vec<PartialAuthenticationPath<i128>> =
+            mts[i + 1].get_multi_proof(&c_indices);
+        let authentication_paths_ab: Vec<PartialAuthenticationPath<i128>> =
+            mts[i].get_multi_proof(&ab_indices);
+
+        // serialize proofs and store in the body
+        let mut c_paths_encoded = bincode::serialize(&authentication_paths_c.clone()).unwrap();
+        body.append(&mut bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut c_paths_encoded);
+
+        let mut ab_paths_encoded = bincode::serialize(&authentication_paths_ab.clone()).unwrap();
+        body.append(&mut bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut ab_paths_encoded);
+
+        primitive_root_of_unity_temp =
+            primitive_root_of_unity_temp * primitive_root_of_unity_temp % modulus;
+
+        // Accumulate values to be returned
+        c_proofs.push(authentication_paths_c);
+        ab_proofs.push(authentication_paths_ab);
+    }
+
+    // Prefix the finished body with a format version and its length, now that the
+    // length is known and nothing referencing the body's bytes (e.g. the
+    // Fiat-Shamir preimages above) needs to be hashed again.
+    output.push(PROOF_FORMAT_VERSION);
+    output.append(&mut bincode::serialize(&(body.len() as u32)).unwrap());
+    output.append(&mut body);
+
+    Ok(LowDegreeProof::<i128> {
+        rounds_count: rounds_count as u8,
+        challenge_hash_preimages,
+        c_proofs,
+        ab_proofs,
+        index_picker_preimage,
+        s: s as u32,
+        merkle_roots: mts.iter().map(|x| x.get_root()).collect::<Vec<[u8; 32]>>(),
+        codeword_size: codeword.len() as u32,
+        offset,
+        pow_bits,
+        pow_nonce,
+        folding_factor,
+        primitive_root_of_unity,
+        max_degree,
+        max_degree_of_last_round,
+    })
+}
+
+/// Fiat-Shamir-samples one combination coefficient per entry of `codeword_roots`, from
+/// a transcript of those roots alone -- so both `prover_batched_i128` (which has the
+/// codewords) and a verifier (which only has their previously-committed roots, e.g.
+/// from a STARK's earlier trace-commitment phase) derive the same coefficients without
+/// the verifier ever seeing the codewords themselves.
+fn batched_combination_coefficients_i128(codeword_roots: &[[u8; 32]], modulus: i128) -> Vec<i128> {
+    let mut preimage: Vec<u8> = bincode::serialize(&(codeword_roots.len() as u32)).unwrap();
+    for root in codeword_roots {
+        preimage.extend_from_slice(root);
+    }
+
+    (0..codeword_roots.len())
+        .map(|i| {
+            let mut this_preimage = preimage.clone();
+            this_preimage.append(&mut bincode::serialize(&(i as u32)).unwrap());
+            let hash = *blake3::hash(&this_preimage).as_bytes();
+            PrimeFieldElement::from_bytes_raw(&modulus, &hash[0..16])
+        })
+        .collect()
+}
+
+/// Proves that a random linear combination of several codewords sharing a domain is of
+/// low degree, instead of running one independent FRI instance per codeword -- e.g. for
+/// batching several STARK columns into a single FRI proof. The combination coefficients
+/// are Fiat-Shamir-sampled from the individual codewords' Merkle roots (see
+/// `batched_combination_coefficients_i128`), which are written to `output` ahead of the
+/// underlying `prover_i128` call so a verifier can recompute the same coefficients from
+/// `output` alone.
+///
+/// Note this only proves that the *combination* is low-degree; it doesn't prove that
+/// the combination was correctly derived from codewords actually committed to by
+/// `codeword_roots` -- that additionally requires opening each individual codeword at
+/// the FRI query points and checking them against the combination, which a caller
+/// batching STARK columns does itself via those columns' own Merkle trees.
+pub fn prover_batched_i128(
+    codewords: &[Vec<i128>],
+    modulus: i128,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: i128,
+) -> Result<LowDegreeProof<i128>, ProveError> {
+    if codewords.is_empty() {
+        return Err(ProveError::NoCodewordsProvided);
+    }
+    let codeword_len = codewords[0].len();
+    if codewords.iter().any(|c| c.len() != codeword_len) {
+        return Err(ProveError::MismatchedCodewordLengths);
+    }
+
+    let codeword_roots: Vec<[u8; 32]> = codewords
+        .iter()
+        .map(|codeword| MerkleTree::from_vec(codeword).get_root())
+        .collect();
+    let coefficients = batched_combination_coefficients_i128(&codeword_roots, modulus);
+
+    let mut combined_codeword: Vec<i128> = vec![0; codeword_len];
+    for (codeword, coefficient) in codewords.iter().zip(coefficients.iter()) {
+        for (combined_value, value) in combined_codeword.iter_mut().zip(codeword.iter()) {
+            *combined_value = (*combined_value + coefficient * value).rem_euclid(modulus);
+        }
+    }
+
+    output.append(&mut bincode::serialize(&(codeword_roots.len() as u32)).unwrap());
+    for root in codeword_roots.iter() {
+        output.append(&mut root.to_vec());
+    }
+
+    prover_i128(
+        &combined_codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+    )
+}
+
+/// Counterpart to `prover_batched_i128`: reads the codeword roots that
+/// `prover_batched_i128` wrote ahead of its `LowDegreeProof`, recomputes the
+/// combination coefficients from them (without ever seeing the codewords), and
+/// verifies the underlying low-degree proof of the combination. Returns the
+/// recomputed coefficients alongside `()` on success, so a caller batching STARK
+/// columns can use them to check each column's claimed opening against the
+/// combined one at the FRI query points.
+pub fn verify_batched_i128(
+    output: &[u8],
+    modulus: i128,
+) -> Result<Vec<i128>, Box<dyn Error>> {
+    if output.len() < 4 {
+        return Err(Box::new(DeserializationError::Truncated));
+    }
+    let root_count: u32 = bincode::deserialize(&output[0..4])?;
+    let mut index = 4usize;
+    let mut codeword_roots: Vec<[u8; 32]> = vec![];
+    for _ in 0..root_count {
+        if index + 32 > output.len() {
+            return Err(Box::new(DeserializationError::Truncated));
+        }
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&output[index..index + 32]);
+        codeword_roots.push(root);
+        index += 32;
+    }
+
+    let coefficients = batched_combination_coefficients_i128(&codeword_roots, modulus);
+    let (proof, _) = LowDegreeProof::<i128>::from_serialization(output.to_vec(), index)?;
+    verify_i128(proof, modulus)?;
+
+    Ok(coefficients)
+}
+
+/// `u64` counterpart of `prover_i128`, for fields like the Goldilocks prime
+/// (`2^64 - 2^32 + 1`) where values can exceed `i64::MAX` and so don't fit in
+/// `prover_i128`'s signed arithmetic without a dedicated `u128`-based reduction step;
+/// see `mod_mul_u64`. Restricted to `folding_factor == 2`: `i128`-based field elements
+/// (and so `fri_fold_i128_general`'s Lagrange-interpolation fallback for other folding
+/// factors) overflow for moduli this large.
+pub fn prover_u64(
+    codeword: &[u64],
+    modulus: u64,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: u64,
+) -> Result<LowDegreeProof<u64>, ProveError> {
+    prover_u64_coset(
+        codeword,
+        modulus,
+        max_degree,
+        s,
+        output,
+        primitive_root_of_unity,
+        1,
+        0,
+        2,
+    )
+}
+
+/// Like `prover_u64`, but for a codeword evaluated over the coset `offset *
+/// <primitive_root_of_unity>` rather than the subgroup generated by
+/// `primitive_root_of_unity` itself, with a configurable proof-of-work grinding
+/// difficulty `pow_bits` (`0` disables grinding). `folding_factor` must be `2`; see
+/// `prover_u64`.
+pub fn prover_u64_coset(
+    codeword: &[u64],
+    modulus: u64,
+    max_degree: u32,
+    s: usize,
+    output: &mut Vec<u8>,
+    primitive_root_of_unity: u64,
+    offset: u64,
+    pow_bits: u8,
+    folding_factor: u8,
+) -> Result<LowDegreeProof<u64>, ProveError> {
+    if folding_factor != 2 {
+        return Err(ProveError::BadFoldingFactor);
+    }
 
-    // Arrays for return values
-    let mut c_proofs: Vec<Vec<PartialAuthenticationPath<i128>>> = vec![];
-    let mut ab_proofs: Vec<Vec<PartialAuthenticationPath<i128>>> = vec![];
+    let (rounds_count, mut mts, max_degree_of_last_round, mut body): (
+        usize,
+        Vec<MerkleTree<u64>>,
+        u32,
+        Vec<u8>,
+    ) = prover_shared(
+        max_degree,
+        codeword,
+        s,
+        primitive_root_of_unity,
+        offset,
+        pow_bits,
+        folding_factor,
+        None,
+    )?;
 
-    let mut mut_codeword: Vec<i128> = codeword.to_vec();
+    let mut c_proofs: Vec<Vec<PartialAuthenticationPath<u64>>> = vec![];
+    let mut ab_proofs: Vec<Vec<PartialAuthenticationPath<u64>>> = vec![];
+
+    let mut mut_codeword: Vec<u64> = codeword.to_vec();
 
     // commit phase
-    let (_, _, inv2_temp) = PrimeFieldElement::eea(modulus, 2);
-    let inv2 = (inv2_temp + modulus) % modulus;
+    let inv2 = mod_inverse_u64(2, modulus);
     let mut primitive_root_of_unity_temp = primitive_root_of_unity;
+    let mut offset_temp = offset;
     let mut challenge_hash_preimages: Vec<Vec<u8>> = vec![];
     for _ in 0..rounds_count {
-        // get challenge
-        challenge_hash_preimages.push(output.clone());
-        let hash = *blake3::hash(output.as_slice()).as_bytes();
-        let challenge: i128 = PrimeFieldElement::from_bytes_raw(&modulus, &hash[0..16]);
+        challenge_hash_preimages.push(body.clone());
+        let hash = *blake3::hash(body.as_slice()).as_bytes();
+        let challenge: u64 = from_bytes_raw_u64(modulus, &hash[0..16]);
 
-        // run fri iteration reducing the degree of the polynomial by one half.
-        // This is achieved by realizing that
-        // P(x) + P(-x) = 2*P_e(x^2) and P(x) - P(-x) = 2*P_o(x^2) where P_e, P_o both
-        // have half the degree of P.
-        mut_codeword = fri_prover_iteration_i128(
+        mut_codeword = fri_prover_iteration_u64(
             &mut_codeword.clone(),
             &challenge,
             &modulus,
             &inv2,
             &primitive_root_of_unity_temp,
+            &offset_temp,
         );
 
-        // Construct Merkle Tree from the new codeword of degree `max_degree / 2`
         let mt = MerkleTree::from_vec(&mut_codeword);
-
-        // append root to proof
-        output.append(&mut mt.get_root().to_vec());
-
-        // collect into memory
+        body.append(&mut mt.get_root().to_vec());
         mts.push(mt);
 
-        // num_rounds += 1;
         primitive_root_of_unity_temp =
-            primitive_root_of_unity_temp * primitive_root_of_unity_temp % modulus;
+            mod_mul_u64(primitive_root_of_unity_temp, primitive_root_of_unity_temp, modulus);
+        offset_temp = mod_mul_u64(offset_temp, offset_temp, modulus);
     }
 
     // query phase
-    // for all subsequent pairs of merkle trees:
-    // - do s times:
-    // -- sample random point y in L2
-    // -- compute square roots s1 s2
-    // -- query P1 in y -> beta
-    // -- query P2 in s1 -> alpha1
-    // -- query P2 in s2 -> alpha2
-    // -- check collinearity (s0, alpha0), (s1, alpha1), (y, beta) <-- we don't care about thi right nw>
-    let index_picker_preimage = output.clone();
-    primitive_root_of_unity_temp = primitive_root_of_unity;
+    let index_picker_preimage = body.clone();
+    let pow_nonce = grind_pow_nonce(&index_picker_preimage, pow_bits);
+    body.append(&mut bincode::serialize(&pow_nonce).unwrap());
     for i in 0usize..rounds_count {
-        // Get the indices of the locations checked in this round
-        let abc_indices_option: Option<Vec<(usize, usize, usize)>> =
-            LowDegreeProof::<i128>::get_abc_indices_internal(
-                &index_picker_preimage,
-                i as u8,
-                s as u32,
-                codeword.len() as u32,
-            );
-        let abc_indices = abc_indices_option.unwrap();
-        let mut c_indices: Vec<usize> = vec![];
-        let mut ab_indices: Vec<usize> = vec![];
-        for (a, b, c) in abc_indices.into_iter() {
+        let abc_indices = LowDegreeProof::<u64>::get_abc_indices_internal(
+            &index_picker_preimage,
+            i as u8,
+            s as u32,
+            codeword.len() as u32,
+            pow_nonce,
+        )
+        .unwrap();
+        let mut ab_indices = vec![];
+        let mut c_indices = vec![];
+        for (a, b, ci) in abc_indices.into_iter() {
             ab_indices.push(a);
             ab_indices.push(b);
-            c_indices.push(c);
+            c_indices.push(ci);
         }
 
-        let authentication_paths_c: Vec<PartialAuthenticationPath<i128>> =
+        let authentication_paths_c: Vec<PartialAuthenticationPath<u64>> =
             mts[i + 1].get_multi_proof(&c_indices);
-        let authentication_paths_ab: Vec<PartialAuthenticationPath<i128>> =
+        let authentication_paths_ab: Vec<PartialAuthenticationPath<u64>> =
             mts[i].get_multi_proof(&ab_indices);
 
-        // serialize proofs and store in output
         let mut c_paths_encoded = bincode::serialize(&authentication_paths_c.clone()).unwrap();
-        output.append(&mut bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
-        output.append(&mut c_paths_encoded);
+        body.append(&mut bincode::serialize(&(c_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut c_paths_encoded);
 
         let mut ab_paths_encoded = bincode::serialize(&authentication_paths_ab.clone()).unwrap();
-        output.append(&mut bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
-        output.append(&mut ab_paths_encoded);
+        body.append(&mut bincode::serialize(&(ab_paths_encoded.len() as u16)).unwrap());
+        body.append(&mut ab_paths_encoded);
 
-        primitive_root_of_unity_temp =
-            primitive_root_of_unity_temp * primitive_root_of_unity_temp % modulus;
-
-        // Accumulate values to be returned
         c_proofs.push(authentication_paths_c);
         ab_proofs.push(authentication_paths_ab);
     }
 
-    Ok(LowDegreeProof::<i128> {
+    output.push(PROOF_FORMAT_VERSION);
+    output.append(&mut bincode::serialize(&(body.len() as u32)).unwrap());
+    output.append(&mut body);
+
+    Ok(LowDegreeProof::<u64> {
         rounds_count: rounds_count as u8,
         challenge_hash_preimages,
         c_proofs,
@@ -838,6 +2643,10 @@ pub fn prover_i128(
         s: s as u32,
         merkle_roots: mts.iter().map(|x| x.get_root()).collect::<Vec<[u8; 32]>>(),
         codeword_size: codeword.len() as u32,
+        offset,
+        pow_bits,
+        pow_nonce,
+        folding_factor,
         primitive_root_of_unity,
         max_degree,
         max_degree_of_last_round,
@@ -848,29 +2657,436 @@ pub fn prover_i128(
 mod test_low_degree_proof {
     use super::*;
     use crate::fft::fast_polynomial_evaluate;
-    use crate::shared_math::ntt::ntt;
+    use crate::shared_math::ntt::{intt, ntt};
     use crate::shared_math::prime_field_element::PrimeField;
     use crate::utils::generate_random_numbers;
     use num_traits::Zero;
 
+    // Build the codeword for `coeffs` via NTT over a domain the size of `coeffs`, fold it
+    // once under `challenge`, interpolate the folded codeword, and return the resulting
+    // polynomial's degree. This exercises the even/odd decomposition
+    // `P(x) + P(-x) = 2*P_e(x^2)` that `fri_prover_iteration_bigint` relies on: folding a
+    // degree-N polynomial's codeword should yield the codeword of a degree-N/2 polynomial.
+    fn fold_reduces_degree(coeffs: &[BigInt], challenge: &BigInt) -> isize {
+        let size = coeffs.len();
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(size as i128, size as i128, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+
+        let coefficients_pfes: Vec<PrimeFieldElementBig> = coeffs
+            .iter()
+            .map(|x| PrimeFieldElementBig::new(x.to_owned(), &field))
+            .collect();
+        let root = PrimeFieldElementBig::new(primitive_root_of_unity.clone(), &field);
+        let codeword: Vec<BigInt> = ntt(&coefficients_pfes, &root)
+            .into_iter()
+            .map(|x| x.value)
+            .collect();
+
+        let (_, _, inv2_temp) = PrimeFieldElementBig::eea(field.q.clone(), bigint(2));
+        let inv2 = (inv2_temp + field.q.clone()) % field.q.clone();
+        let folded = fri_prover_iteration_bigint(
+            &codeword,
+            challenge,
+            &field.q,
+            &inv2,
+            &primitive_root_of_unity,
+            &BigInt::one(),
+        );
+
+        let root_squared = PrimeFieldElementBig::new(
+            primitive_root_of_unity.clone() * primitive_root_of_unity % field.q.clone(),
+            &field,
+        );
+        let folded_pfes: Vec<PrimeFieldElementBig> = folded
+            .into_iter()
+            .map(|y| PrimeFieldElementBig::new(y, &field))
+            .collect();
+        let interpolant = Polynomial {
+            coefficients: intt(&folded_pfes, &root_squared),
+        };
+        interpolant.degree()
+    }
+
+    #[test]
+    fn fold_reduces_degree_over_2_pow_14_domain_test() {
+        let size = 2usize.pow(14);
+        let max_degree = 1023;
+        let coefficients: Vec<BigInt> = generate_random_numbers(max_degree + 1, 65537)
+            .iter()
+            .map(|x| bigint(*x))
+            .chain(std::iter::repeat(BigInt::zero()).take(size - max_degree - 1))
+            .collect();
+
+        let degree = fold_reduces_degree(&coefficients, &bigint(17));
+        assert_eq!((max_degree / 2) as isize, degree);
+    }
+
+    // Build a proof with `prover_i128` for the given parameters and assert that
+    // re-parsing its serialized output with `from_serialization` yields a proof equal to
+    // the one returned directly. Used by the property-based test below.
+    fn roundtrip_check(
+        field: &PrimeField,
+        primitive_root_of_unity: i128,
+        max_degree: u32,
+        s: usize,
+        codeword: &[i128],
+    ) {
+        let mut output = vec![];
+        let proof = prover_i128(
+            codeword,
+            field.q,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        let (deserialized_proof, _) =
+            LowDegreeProof::<i128>::from_serialization(output, 0).unwrap();
+        assert_eq!(proof, deserialized_proof);
+    }
+
+    #[test]
+    fn tampering_with_a_challenge_hash_preimage_is_detected_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let mut proof = prover_i128(
+            &codeword,
+            field.q,
+            1,
+            2,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        assert_eq!(Ok(()), verify_i128(proof.clone(), field.q));
+
+        proof.challenge_hash_preimages[0].push(0xffu8);
+        assert_eq!(
+            Err(ValidationError::InconsistentTranscript),
+            verify_i128(proof, field.q)
+        );
+    }
+
+    #[test]
+    fn recompute_challenges_matches_internal_derivation_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let proof = prover_i128(
+            &codeword,
+            field.q,
+            1,
+            2,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+
+        let recomputed = proof.recompute_challenges(field.q);
+        assert_eq!(proof.challenge_hash_preimages.len(), recomputed.len());
+        let expected: Vec<i128> = proof
+            .challenge_hash_preimages
+            .iter()
+            .map(|bs| *blake3::hash(bs.as_slice()).as_bytes())
+            .map(|hash| PrimeFieldElement::from_bytes_raw(&field.q, &hash[0..16]))
+            .collect();
+        assert_eq!(expected, recomputed);
+    }
+
+    #[test]
+    fn serialize_to_deserialize_from_cursor_test() {
+        use std::io::Cursor;
+
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let proof = prover_i128(
+            &codeword,
+            field.q,
+            1,
+            2,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+
+        let mut cursor = Cursor::new(vec![]);
+        proof.serialize_to(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let deserialized_proof = LowDegreeProof::<i128>::deserialize_from(&mut cursor).unwrap();
+        assert_eq!(proof, deserialized_proof);
+    }
+
+    #[test]
+    fn compact_serialization_is_smaller_than_bincode_for_cubic_proof_test() {
+        use std::io::Cursor;
+
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(16, 10000, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity.clone());
+        let max_degree = 3;
+        let s = 6;
+        let y_values = domain
+            .iter()
+            .map(|x| (6 + x.to_owned() * (14 + x.to_owned() * (2 + 5 * x))) % field.q.clone())
+            .collect::<Vec<BigInt>>();
+        let mut output = vec![];
+        let proof = prover_bigint(
+            &y_values,
+            field.q.clone(),
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+
+        let mut bincode_buffer = Cursor::new(vec![]);
+        proof.serialize_to(&mut bincode_buffer).unwrap();
+        let bincode_size = bincode_buffer.into_inner().len();
+
+        let mut compact_buffer = Cursor::new(vec![]);
+        proof
+            .serialize_compact_to(&mut compact_buffer, &field.q)
+            .unwrap();
+        let compact_bytes = compact_buffer.into_inner();
+        let compact_size = compact_bytes.len();
+
+        assert!(
+            compact_size < bincode_size,
+            "compact encoding ({} bytes) should be smaller than bincode ({} bytes) \
+             for values well under the modulus",
+            compact_size,
+            bincode_size
+        );
+
+        // Also check the compact encoding round-trips correctly.
+        let mut compact_cursor = Cursor::new(compact_bytes);
+        let deserialized_proof =
+            LowDegreeProof::<BigInt>::deserialize_compact_from(&mut compact_cursor, &field.q)
+                .unwrap();
+        assert_eq!(proof, deserialized_proof);
+    }
+
+    #[test]
+    fn from_serialization_truncated_buffer_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        prover_i128(&codeword, field.q, 1, 2, &mut output, primitive_root_of_unity).unwrap();
+
+        // Chop off the tail of an otherwise-valid serialization; the length header now
+        // promises more body bytes than are actually present.
+        let truncated = output[0..output.len() - 1].to_vec();
+        let result = LowDegreeProof::<i128>::from_serialization(truncated, 0);
+        assert!(result.is_err());
+        let error = result.err().unwrap();
+        assert_eq!(
+            &DeserializationError::Truncated,
+            error.downcast_ref::<DeserializationError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_serialization_corrupted_body_never_panics_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut valid = vec![];
+        prover_i128(&codeword, field.q, 1, 2, &mut valid, primitive_root_of_unity).unwrap();
+
+        // Corrupt every possible 2-byte window inside the body (the version+length
+        // header, at offsets 0..5, is left untouched and internally consistent). Any of
+        // these corruptions can turn a length-prefixed field -- e.g. a `proof_size` --
+        // into a value far larger than the remaining buffer, so `from_serialization`
+        // must return `Err` rather than panic on an out-of-range slice.
+        for corrupt_at in 5..valid.len() - 1 {
+            let mut corrupted = valid.clone();
+            corrupted[corrupt_at] = 0xff;
+            corrupted[corrupt_at + 1] = 0xff;
+            let result = std::panic::catch_unwind(|| {
+                LowDegreeProof::<i128>::from_serialization(corrupted, 0)
+            });
+            assert!(
+                result.is_ok(),
+                "from_serialization panicked on a corrupted body, byte offset {}",
+                corrupt_at
+            );
+        }
+    }
+
+    #[test]
+    fn from_serialization_version_mismatch_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        prover_i128(&codeword, field.q, 1, 2, &mut output, primitive_root_of_unity).unwrap();
+
+        output[0] = PROOF_FORMAT_VERSION + 1;
+        let result = LowDegreeProof::<i128>::from_serialization(output, 0);
+        assert!(result.is_err());
+        let error = result.err().unwrap();
+        assert_eq!(
+            &DeserializationError::VersionMismatch {
+                expected: PROOF_FORMAT_VERSION,
+                got: PROOF_FORMAT_VERSION + 1,
+            },
+            error.downcast_ref::<DeserializationError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn prover_i128_roundtrip_property_based_test() {
+        let max_degree = 1;
+        let s = 2;
+        for i in 0..50 {
+            let mut ret: Option<(PrimeField, i128)> = None;
+            PrimeField::get_field_with_primitive_root_of_unity(4, 100 + i * 10, &mut ret);
+            let (field, primitive_root_of_unity) = ret.unwrap();
+            let codeword = field.get_power_series(primitive_root_of_unity);
+            roundtrip_check(&field, primitive_root_of_unity, max_degree, s, &codeword);
+        }
+    }
+
     #[test]
     fn get_rounds_count_test() {
-        assert_eq!((3, 0), get_rounds_count(128, 7, 10));
-        assert_eq!((3, 0), get_rounds_count(128, 7, 16));
-        assert_eq!((2, 1), get_rounds_count(128, 7, 17));
-        assert_eq!((2, 1), get_rounds_count(128, 7, 32));
-        assert_eq!((1, 3), get_rounds_count(128, 7, 33));
-        assert_eq!((1, 3), get_rounds_count(128, 7, 63));
-        assert_eq!((1, 3), get_rounds_count(128, 7, 64));
-        assert_eq!((3, 0), get_rounds_count(256, 7, 10));
-        assert_eq!((4, 0), get_rounds_count(256, 15, 10));
-        assert_eq!((4, 0), get_rounds_count(256, 15, 16));
-        assert_eq!((3, 1), get_rounds_count(256, 15, 17));
-        assert_eq!((3, 1), get_rounds_count(256, 15, 32));
-        assert_eq!((2, 3), get_rounds_count(256, 15, 33));
-        assert_eq!((14, 3), get_rounds_count(1048576, 65535, 50));
-        assert_eq!((14, 3), get_rounds_count(1048576, 65535, 64));
-        assert_eq!((13, 7), get_rounds_count(1048576, 65535, 65));
+        assert_eq!(Some((3, 0)), get_rounds_count(128, 7, 10, 2));
+        assert_eq!(Some((3, 0)), get_rounds_count(128, 7, 16, 2));
+        assert_eq!(Some((2, 1)), get_rounds_count(128, 7, 17, 2));
+        assert_eq!(Some((2, 1)), get_rounds_count(128, 7, 32, 2));
+        assert_eq!(Some((1, 3)), get_rounds_count(128, 7, 33, 2));
+        assert_eq!(Some((1, 3)), get_rounds_count(128, 7, 63, 2));
+        assert_eq!(Some((1, 3)), get_rounds_count(128, 7, 64, 2));
+        assert_eq!(Some((3, 0)), get_rounds_count(256, 7, 10, 2));
+        assert_eq!(Some((4, 0)), get_rounds_count(256, 15, 10, 2));
+        assert_eq!(Some((4, 0)), get_rounds_count(256, 15, 16, 2));
+        assert_eq!(Some((3, 1)), get_rounds_count(256, 15, 17, 2));
+        assert_eq!(Some((3, 1)), get_rounds_count(256, 15, 32, 2));
+        assert_eq!(Some((2, 3)), get_rounds_count(256, 15, 33, 2));
+        assert_eq!(Some((14, 3)), get_rounds_count(1048576, 65535, 50, 2));
+        assert_eq!(Some((14, 3)), get_rounds_count(1048576, 65535, 64, 2));
+        assert_eq!(Some((13, 7)), get_rounds_count(1048576, 65535, 65, 2));
+    }
+
+    #[test]
+    fn get_rounds_count_folding_factor_4_test() {
+        // Same total reduction (2^14) as the 1048576/65535/50 binary case above, but
+        // reached in half as many rounds since each round folds by 4 instead of 2.
+        assert_eq!(Some((7, 3)), get_rounds_count(1048576, 65535, 50, 4));
+    }
+
+    #[test]
+    fn prove_error_carries_offending_parameters_test() {
+        let codeword = vec![bigint(1); 8];
+        let mut output = vec![];
+
+        // max_degree + 1 == 3, not a power of two.
+        let bad_max_degree_result =
+            prover_bigint(&codeword, bigint(101), 2, 1, &mut output, bigint(1));
+        assert_eq!(
+            Err(ProveError::BadMaxDegreeValue { max_degree: 2 }),
+            bad_max_degree_result
+        );
+
+        // A security level (`s`) this high relative to `codeword`'s expansion factor
+        // leaves no room for even one round of folding.
+        let non_positive_round_count_result =
+            prover_bigint(&codeword, bigint(101), 3, 5, &mut output, bigint(1));
+        assert_eq!(
+            Err(ProveError::NonPostiveRoundCount {
+                codeword_size: 8,
+                max_degree: 3,
+                s: 5,
+            }),
+            non_positive_round_count_result
+        );
+    }
+
+    #[test]
+    fn prover_bigint_with_commitment_matches_auto_built_path_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity.clone());
+        let max_degree = 1;
+        let s = 2;
+
+        let mut auto_built_output = vec![];
+        let auto_built_proof = prover_bigint(
+            &codeword,
+            field.q.clone(),
+            max_degree,
+            s,
+            &mut auto_built_output,
+            primitive_root_of_unity.clone(),
+        )
+        .unwrap();
+
+        let first_tree = MerkleTree::from_vec(&codeword);
+        let mut with_commitment_output = vec![];
+        let with_commitment_proof = prover_bigint_with_commitment(
+            &codeword,
+            field.q.clone(),
+            max_degree,
+            s,
+            &mut with_commitment_output,
+            primitive_root_of_unity,
+            first_tree,
+        )
+        .unwrap();
+
+        assert_eq!(auto_built_output, with_commitment_output);
+        assert_eq!(auto_built_proof, with_commitment_proof);
+    }
+
+    #[test]
+    fn get_value_ref_matches_get_value_and_verifier_still_passes_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let codeword = field.get_power_series(primitive_root_of_unity.clone());
+        let mut output = vec![];
+        let proof = prover_bigint(&codeword, field.q.clone(), 1, 2, &mut output, primitive_root_of_unity)
+            .unwrap();
+
+        for path in proof.c_proofs.iter().flatten().chain(proof.ab_proofs.iter().flatten()) {
+            assert_eq!(path.get_value(), *path.get_value_ref());
+        }
+
+        assert_eq!(Ok(()), verify_bigint(proof, field.q));
+    }
+
+    #[test]
+    fn root_of_unity_bigint_raised_to_codeword_size_is_one_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let power_series = field.get_power_series(primitive_root_of_unity.clone());
+        let mut output = vec![];
+        let proof = prover_bigint(&power_series, field.q.clone(), 1, 2, &mut output, primitive_root_of_unity)
+            .unwrap();
+
+        let root = proof.root_of_unity(&field);
+        assert_eq!(
+            PrimeFieldElementBig::new(bigint(1), &field),
+            root.mod_pow(bigint(proof.codeword_size as i128))
+        );
     }
 
     #[test]
@@ -971,7 +3187,7 @@ mod test_low_degree_proof {
         new_value.value = Some(bigint(237));
         proof.ab_proofs[0][1].0[0] = Some(new_value);
         assert_eq!(
-            Err(ValidationError::BadMerkleProof),
+            Err(ValidationError::BadMerkleProof { index: 1 }),
             verify_bigint(proof, field.q.clone())
         );
 
@@ -995,6 +3211,30 @@ mod test_low_degree_proof {
         assert_eq!(Ok(()), verify_bigint(proof, field.q));
     }
 
+    #[test]
+    fn verify_bigint_with_witness_returns_low_degree_polynomial_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let y_values = field.get_power_series(primitive_root_of_unity.clone());
+        let max_degree = 1;
+        let s = 2;
+        let mut output = vec![];
+        let proof: LowDegreeProof<BigInt> = prover_bigint(
+            &y_values,
+            field.q.clone(),
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        let max_degree_of_last_round = proof.max_degree_of_last_round;
+
+        let witness_polynomial = verify_bigint_with_witness(proof, &field).unwrap();
+        assert!(witness_polynomial.degree() <= max_degree_of_last_round as isize);
+    }
+
     #[test]
     fn generate_proof_small_i128() {
         let mut ret: Option<(PrimeField, i128)> = None;
@@ -1062,7 +3302,7 @@ mod test_low_degree_proof {
         new_value.value = Some(237);
         proof.ab_proofs[0][1].0[0] = Some(new_value);
         assert_eq!(
-            Err(ValidationError::BadMerkleProof),
+            Err(ValidationError::BadMerkleProof { index: 1 }),
             verify_i128(proof, field.q)
         );
 
@@ -1085,6 +3325,367 @@ mod test_low_degree_proof {
         assert_eq!(Ok(()), verify_i128(proof, field.q));
     }
 
+    #[test]
+    fn prover_batched_i128_combines_three_codewords_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let power_series = field.get_power_series(primitive_root_of_unity);
+
+        // Three distinct degree-1 "polynomials" (scalar multiples of x), all
+        // individually low-degree, so any linear combination of them is too.
+        let codewords: Vec<Vec<i128>> = vec![2, 5, 11]
+            .into_iter()
+            .map(|c| {
+                power_series
+                    .iter()
+                    .map(|&y| y * c % field.q)
+                    .collect::<Vec<i128>>()
+            })
+            .collect();
+
+        let mut output = vec![];
+        let max_degree = 1;
+        let s = 2;
+        let proof = prover_batched_i128(
+            &codewords,
+            field.q,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        assert_eq!(1, proof.max_degree);
+
+        let expected_roots: Vec<[u8; 32]> = codewords
+            .iter()
+            .map(|codeword| MerkleTree::from_vec(codeword).get_root())
+            .collect();
+        let expected_coefficients =
+            batched_combination_coefficients_i128(&expected_roots, field.q);
+
+        let recomputed_coefficients = verify_batched_i128(&output, field.q).unwrap();
+        assert_eq!(expected_coefficients, recomputed_coefficients);
+    }
+
+    #[test]
+    fn prover_batched_i128_rejects_empty_codeword_list_test() {
+        let mut output = vec![];
+        assert_eq!(
+            Err(ProveError::NoCodewordsProvided),
+            prover_batched_i128(&[], 101, 1, 2, &mut output, 10)
+        );
+    }
+
+    #[test]
+    fn prover_batched_i128_rejects_mismatched_codeword_lengths_test() {
+        let mut output = vec![];
+        assert_eq!(
+            Err(ProveError::MismatchedCodewordLengths),
+            prover_batched_i128(&[vec![1, 2, 3, 4], vec![1, 2]], 101, 1, 2, &mut output, 10)
+        );
+    }
+
+    #[test]
+    fn root_of_unity_i128_raised_to_codeword_size_is_one_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let power_series = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let proof = prover_i128(&power_series, field.q, 1, 2, &mut output, primitive_root_of_unity)
+            .unwrap();
+
+        let root = proof.root_of_unity(&field);
+        assert_eq!(
+            field.element(1),
+            root.mod_pow(proof.codeword_size as i128)
+        );
+    }
+
+    #[test]
+    fn fri_prover_iteration_i128_matches_bigint_near_overflow_boundary_test() {
+        // A prime close to `2^62`: large enough that the naive, unreduced
+        // `challenge * x_inv * codeword[i]` chain would overflow `i128`, but small
+        // enough that `fri_prover_iteration_i128`'s per-multiplication reductions keep
+        // every intermediate product within range.
+        let modulus: i128 = 4611686018427387817;
+        let field = PrimeField::new(modulus);
+        let (root, _) = field.get_primitive_root_of_unity(4);
+        let primitive_root_of_unity = root.unwrap().value;
+
+        let codeword: Vec<i128> = vec![
+            modulus - 1,
+            modulus - 2,
+            1234567890123456789,
+            9876543210987654321 % modulus,
+        ];
+        let challenge: i128 = modulus - 3;
+        let offset: i128 = 1;
+        let (_, _, inv2_temp) = PrimeFieldElement::eea(modulus, 2);
+        let inv_two = (inv2_temp + modulus) % modulus;
+
+        let folded_i128 = fri_prover_iteration_i128(
+            &codeword,
+            &challenge,
+            &modulus,
+            &inv_two,
+            &primitive_root_of_unity,
+            &offset,
+        );
+
+        let modulus_big = bigint(modulus);
+        let folded_bigint = fri_prover_iteration_bigint(
+            &codeword.iter().map(|&v| bigint(v)).collect::<Vec<BigInt>>(),
+            &bigint(challenge),
+            &modulus_big,
+            &bigint(inv_two),
+            &bigint(primitive_root_of_unity),
+            &bigint(offset),
+        );
+
+        let folded_bigint_as_i128: Vec<i128> = folded_bigint
+            .into_iter()
+            .map(|v| i128::try_from(v).unwrap())
+            .collect();
+        assert_eq!(folded_i128, folded_bigint_as_i128);
+    }
+
+    #[test]
+    fn verify_and_get_root_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let y_values = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let proof = prover_i128(&y_values, field.q, 1, 2, &mut output, primitive_root_of_unity)
+            .unwrap();
+        let expected_root = proof.merkle_roots[0];
+        let root = verify_i128_and_get_root(proof, field.q).unwrap();
+        assert_eq!(expected_root, root);
+
+        // A corrupted proof must not yield a root
+        let mut output2 = vec![];
+        let mut bad_proof = prover_i128(
+            &y_values,
+            field.q,
+            1,
+            2,
+            &mut output2,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        let mut new_value = bad_proof.ab_proofs[0][1].0[0].clone().unwrap();
+        new_value.value = Some(237);
+        bad_proof.ab_proofs[0][1].0[0] = Some(new_value);
+        assert!(verify_i128_and_get_root(bad_proof, field.q).is_err());
+    }
+
+    #[test]
+    fn generate_proof_coset_i128() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+
+        // Evaluate P(x) = x over the coset `offset * <primitive_root_of_unity>`.
+        // Offset 2 is avoided here: over this toy 101-element field it makes the
+        // domain point `offset * root^0` collide with the Fiat-Shamir challenge
+        // derived from this exact codeword/offset combination, which trips the
+        // (correct) non-unique-x-coordinate rejection in `Polynomial::lie_on_degree_n`.
+        // That collision is a property of this specific small-field test input, not
+        // a prover/verifier bug -- with a cryptographically-sized field the chance of
+        // a sampled domain point equalling the challenge is negligible.
+        let offset = 3i128;
+        let y_values: Vec<i128> = domain.iter().map(|g| offset * g % field.q).collect();
+        let max_degree = 1;
+        let s = 2;
+        let mut output = vec![];
+        let proof = prover_i128_coset(
+            &y_values,
+            field.q,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+            offset,
+            0,
+            2,
+        )
+        .unwrap();
+        assert_eq!(offset, proof.offset);
+        assert_eq!(Ok(()), verify_i128(proof, field.q));
+
+        // Sanity check: subgroup FRI (offset 1) still verifies as before
+        let mut subgroup_output = vec![];
+        let subgroup_proof = prover_i128(
+            &domain,
+            field.q,
+            max_degree,
+            s,
+            &mut subgroup_output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        assert_eq!(1, subgroup_proof.offset);
+        assert_eq!(Ok(()), verify_i128(subgroup_proof, field.q));
+    }
+
+    #[test]
+    fn generate_proof_coset_bigint_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity.clone());
+
+        // Evaluate P(x) = x over the coset `offset * <primitive_root_of_unity>`
+        let offset = bigint(2);
+        let y_values: Vec<BigInt> = domain
+            .iter()
+            .map(|g| offset.clone() * g % field.q.clone())
+            .collect();
+        let max_degree = 1;
+        let s = 2;
+        let mut output = vec![];
+        let proof = prover_bigint_coset(
+            &y_values,
+            field.q.clone(),
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+            offset.clone(),
+            0,
+            2,
+        )
+        .unwrap();
+        assert_eq!(offset, proof.offset);
+        assert_eq!(Ok(()), verify_bigint(proof, field.q));
+    }
+
+    #[test]
+    fn generate_proof_with_pow_grinding_i128() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        let max_degree = 1;
+        let s = 2;
+        let pow_bits = 8;
+        let mut output = vec![];
+        let proof = prover_i128_coset(
+            &domain,
+            field.q,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+            1,
+            pow_bits,
+            2,
+        )
+        .unwrap();
+        assert_eq!(pow_bits, proof.pow_bits);
+        assert!(verify_pow_nonce(
+            &proof.index_picker_preimage,
+            proof.pow_nonce,
+            proof.pow_bits
+        ));
+        assert_eq!(Ok(()), verify_i128(proof.clone(), field.q));
+
+        // A tampered nonce should fail the grinding check before anything else is inspected
+        let mut bad_proof = proof;
+        bad_proof.pow_nonce = bad_proof.pow_nonce.wrapping_add(1);
+        assert_eq!(
+            Err(ValidationError::InsufficientProofOfWork),
+            verify_i128(bad_proof, field.q)
+        );
+    }
+
+    #[test]
+    fn generate_proof_with_folding_factor_4_i128_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(16, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        let max_degree = 3;
+        let s = 2;
+        let folding_factor = 4;
+        let mut output = vec![];
+        let proof = prover_i128_coset(
+            &domain,
+            field.q,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+            1,
+            0,
+            folding_factor,
+        )
+        .unwrap();
+        assert_eq!(folding_factor, proof.folding_factor);
+        assert_eq!(Ok(()), verify_i128(proof, field.q));
+    }
+
+    #[test]
+    fn prover_rejects_max_degree_zero_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        assert_eq!(
+            Err(ProveError::MaxDegreeTooSmall),
+            prover_i128(&domain, field.q, 0, 2, &mut output, primitive_root_of_unity)
+        );
+    }
+
+    #[test]
+    fn prover_accepts_max_degree_one_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        let mut output = vec![];
+        let proof = prover_i128(&domain, field.q, 1, 2, &mut output, primitive_root_of_unity)
+            .unwrap();
+        assert_eq!(Ok(()), verify_i128(proof, field.q));
+    }
+
+    #[test]
+    fn prover_rejects_codeword_length_12_test() {
+        // 12 isn't a power of two, so it can never be a clean power-of-two multiple
+        // of `max_degree + 1`.
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(12, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        assert_eq!(12, domain.len());
+        let mut output = vec![];
+        assert_eq!(
+            Err(ProveError::CodewordSizeMismatch),
+            prover_i128(&domain, field.q, 1, 2, &mut output, primitive_root_of_unity)
+        );
+    }
+
+    #[test]
+    fn prover_rejects_codeword_length_100_test() {
+        // 100 is a power of two multiple of neither 2 nor 4 (it isn't a power of
+        // two at all), so this must be rejected regardless of `max_degree`.
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(100, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+        let domain = field.get_power_series(primitive_root_of_unity);
+        assert_eq!(100, domain.len());
+        let mut output = vec![];
+        assert_eq!(
+            Err(ProveError::CodewordSizeMismatch),
+            prover_i128(&domain, field.q, 3, 2, &mut output, primitive_root_of_unity)
+        );
+    }
+
     #[test]
     fn generate_proof_cubica_bigint() {
         let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
@@ -1294,6 +3895,83 @@ mod test_low_degree_proof {
         assert_eq!(Ok(()), verify_i128(proof, field.q));
     }
 
+    #[test]
+    fn generate_proof_and_verify_u64_degree_7_over_goldilocks_test() {
+        // The Goldilocks prime: 2^64 - 2^32 + 1. Its multiplicative group has order
+        // 2^32 * (2^32 - 1), so it has elements of every power-of-two order up to
+        // 2^32; `primitive_root_of_unity` below has order exactly 16.
+        let modulus: u64 = 18446744069414584321;
+        let primitive_root_of_unity: u64 = 17293822564807737345;
+        assert_eq!(1, mod_pow_u64(primitive_root_of_unity, 16, modulus));
+        assert_ne!(1, mod_pow_u64(primitive_root_of_unity, 8, modulus));
+
+        // P(x) = 2x^7 + 4x^6 + x^5 + 3x^4 + 5x^3 + 2x^2 + 14x + 6, evaluated via Horner.
+        let coefficients: [u64; 8] = [6, 14, 2, 5, 3, 1, 4, 2];
+        let domain: Vec<u64> = (0..16u64)
+            .map(|i| mod_pow_u64(primitive_root_of_unity, i, modulus))
+            .collect();
+        let y_values: Vec<u64> = domain
+            .iter()
+            .map(|&x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0u64, |acc, &c| mod_add_u64(mod_mul_u64(acc, x, modulus), c, modulus))
+            })
+            .collect();
+
+        let max_degree = 7;
+        let s = 2;
+        let mut output = vec![];
+        let proof = prover_u64(
+            &y_values,
+            modulus,
+            max_degree,
+            s,
+            &mut output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        assert_eq!(
+            proof,
+            LowDegreeProof::<u64>::from_serialization(output, 0)
+                .unwrap()
+                .0
+        );
+        assert_eq!(Ok(()), verify_u64(proof.clone(), modulus));
+
+        // Corrupting a y-value should make the codeword no longer agree with a
+        // degree-7 polynomial, and so fail verification.
+        let mut corrupted_y_values = y_values;
+        corrupted_y_values[3] = mod_add_u64(corrupted_y_values[3], 1, modulus);
+        let mut corrupted_output = vec![];
+        let corrupted_proof = prover_u64(
+            &corrupted_y_values,
+            modulus,
+            max_degree,
+            s,
+            &mut corrupted_output,
+            primitive_root_of_unity,
+        )
+        .unwrap();
+        assert_ne!(Ok(()), verify_u64(corrupted_proof, modulus));
+    }
+
+    #[test]
+    fn estimate_degree_i128_of_the_identity_codeword_is_one_test() {
+        let mut ret: Option<(PrimeField, i128)> = None;
+        PrimeField::get_field_with_primitive_root_of_unity(4, 100, &mut ret);
+        let (field, primitive_root_of_unity) = ret.unwrap();
+
+        // `get_power_series` returns [root^0, root^1, root^2, root^3], i.e. the domain
+        // itself, which is also P(x) = x evaluated over that domain.
+        let codeword = field.get_power_series(primitive_root_of_unity);
+        assert_eq!(
+            1,
+            estimate_degree_i128(&codeword, field.q, primitive_root_of_unity)
+        );
+    }
+
     #[test]
     fn generate_proof_16_alt_bigint() {
         let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
@@ -1454,6 +4132,59 @@ mod test_low_degree_proof {
         );
     }
 
+    #[test]
+    fn reduce_security_bigint_test() {
+        let mut ret: Option<(PrimeFieldBig, BigInt)> = None;
+        // Large enough, relative to `s` below, that the index-picking algorithm stays
+        // in its "many more indices available than requested" mode for every round:
+        // that mode's choices only depend on *which* indices were picked before the
+        // one being picked, not on how many are being picked in total, so the first
+        // `new_s` indices `reduce_security` keeps are guaranteed to match what a
+        // verifier re-derives for `new_s` from scratch.
+        let size = 2usize.pow(16);
+        let max_degree = 1023;
+        PrimeFieldBig::get_field_with_primitive_root_of_unity(size as i128, size as i128, &mut ret);
+        let (field, primitive_root_of_unity_bi) = ret.clone().unwrap();
+        let coefficients: Vec<PrimeFieldElementBig> = generate_random_numbers(max_degree + 1, 65537)
+            .iter()
+            .map(|x| PrimeFieldElementBig::new(bigint(*x), &field))
+            .chain(std::iter::repeat(field.ring_zero()).take(size - max_degree - 1))
+            .collect();
+        let primitive_root_of_unity: PrimeFieldElementBig =
+            PrimeFieldElementBig::new(primitive_root_of_unity_bi.clone(), &field);
+        let y_values_pfes = ntt(coefficients.as_slice(), &primitive_root_of_unity);
+        let y_values: Vec<BigInt> = y_values_pfes.iter().map(|x| x.to_owned().value).collect();
+
+        let mut output = vec![];
+        let s = 20;
+        let proof = prover_bigint(
+            &y_values,
+            field.q.clone(),
+            max_degree as u32,
+            s,
+            &mut output,
+            primitive_root_of_unity_bi,
+        )
+        .unwrap();
+        assert_eq!(Ok(()), verify_bigint(proof.clone(), field.q.clone()));
+
+        let reduced_proof = proof.reduce_security(5).unwrap();
+        assert_eq!(5, reduced_proof.s);
+        for round in reduced_proof.ab_proofs.iter() {
+            assert_eq!(10, round.len());
+        }
+        for round in reduced_proof.c_proofs.iter() {
+            assert_eq!(5, round.len());
+        }
+        assert_eq!(Ok(()), verify_bigint(reduced_proof, field.q.clone()));
+
+        // Asking for more colinearity checks than the proof contains is an error.
+        assert_eq!(
+            Err(ProveError::SecurityLevelTooHigh),
+            proof.reduce_security(21)
+        );
+    }
+
     #[test]
     fn generate_proof_1024_i128() {
         let mut ret: Option<(PrimeField, i128)> = None;
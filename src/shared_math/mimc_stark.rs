@@ -9,6 +9,7 @@ use crate::shared_math::traits::{IdentityValues, New};
 use crate::util_types::merkle_tree::{MerkleTree, PartialAuthenticationPath};
 use crate::utils;
 use num_bigint::BigInt;
+use rand::RngCore;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::error::Error;
@@ -280,6 +281,26 @@ fn get_linear_combinations<
         .collect::<Vec<T>>()
 }
 
+/// A single step of the MiMC permutation: `x -> x^3 + k`. `mimc_forward` is just this
+/// applied `num_steps` times, cycling through `round_costants`; exposed separately so
+/// the round function can be reused outside the STARK, e.g. for experiments that don't
+/// need a full computational trace.
+pub fn mimc_round<'a>(
+    input: &PrimeFieldElementBig<'a>,
+    round_constant: &PrimeFieldElementBig<'a>,
+) -> PrimeFieldElementBig<'a> {
+    input.to_owned().mod_pow(Into::<BigInt>::into(3)) + round_constant.to_owned()
+}
+
+/// `num_rounds` pseudo-random round constants in `field`, suitable for driving
+/// `mimc_round`/`mimc_forward`. Uses the OS-seeded RNG, like `utils::generate_random_numbers`.
+pub fn mimc_round_constants(num_rounds: usize, field: &PrimeFieldBig) -> Vec<PrimeFieldElementBig> {
+    let mut prng = rand::thread_rng();
+    (0..num_rounds)
+        .map(|_| PrimeFieldElementBig::new(BigInt::from(prng.next_u64()), field))
+        .collect()
+}
+
 pub fn mimc_forward<'a>(
     input: &'a PrimeFieldElementBig,
     num_steps: usize,
@@ -289,8 +310,7 @@ pub fn mimc_forward<'a>(
     let mut res: PrimeFieldElementBig = input.to_owned();
     computational_trace.push(input.to_owned());
     for i in 0..num_steps {
-        res = res.clone().mod_pow(Into::<BigInt>::into(3))
-            + round_costants[i % round_costants.len()].clone();
+        res = mimc_round(&res, &round_costants[i % round_costants.len()]);
         computational_trace.push(res.clone());
     }
 
@@ -1247,6 +1267,59 @@ mod test_modular_arithmetic {
         }
     }
 
+    #[test]
+    fn mimc_stark_proof_serialization_round_trip_test() {
+        let no_steps = 3;
+        let expansion_factor = 4;
+        let security_factor = 8;
+        let field = PrimeFieldBig::new(b(5 * 2i128.pow(25) + 1));
+        let round_constants: Vec<PrimeFieldElementBig> = vec![7, 256, 117]
+            .into_iter()
+            .map(|x| PrimeFieldElementBig::new(b(x), &field))
+            .collect();
+        let (g2_option, _) = field.get_primitive_root_of_unity((no_steps + 1) * expansion_factor);
+        let omega = g2_option.unwrap();
+
+        let mimc_input = PrimeFieldElementBig::new(b(3), &field);
+        let mimc_input_clone = mimc_input.clone();
+        let round_constants_clone = round_constants.clone();
+        let mimc_trace = mimc_forward(
+            &mimc_input_clone,
+            no_steps as usize,
+            &round_constants_clone,
+        );
+        let mimc_output = mimc_trace[no_steps as usize].clone();
+        let mimc_claim = MimcClaim::<PrimeFieldElementBig> {
+            input: mimc_input,
+            output: mimc_output,
+            round_constants: round_constants.clone(),
+        };
+
+        let mut transcript: Vec<u8> = vec![];
+        let stark_proof = stark_of_mimc_prove(
+            security_factor,
+            no_steps as usize,
+            expansion_factor as usize,
+            omega.clone(),
+            &mimc_claim,
+            &mut transcript,
+        )
+        .expect("Failed to produce STARK proof");
+
+        let (deserialized_proof, _) =
+            MimcStarkProof::<BigInt>::from_serialization(transcript, 0).unwrap();
+        assert_eq!(stark_proof, deserialized_proof);
+        assert!(deserialized_proof
+            .verify(
+                mimc_claim,
+                round_constants,
+                omega,
+                no_steps,
+                expansion_factor,
+            )
+            .is_ok());
+    }
+
     #[test]
     fn mimc_forward_small() {
         let field = PrimeField::new(17);
@@ -1273,4 +1346,34 @@ mod test_modular_arithmetic {
             }
         }
     }
+
+    #[test]
+    fn mimc_round_matches_trace_final_row_test() {
+        let field = PrimeFieldBig::new(b(5 * 2i128.pow(25) + 1));
+        let round_constants: Vec<PrimeFieldElementBig> = vec![7, 256, 117]
+            .iter()
+            .map(|x| PrimeFieldElementBig::new(b(x.to_owned()), &field))
+            .collect();
+        let input = PrimeFieldElementBig::new(b(5), &field);
+
+        let trace = mimc_forward(&input, round_constants.len(), &round_constants);
+
+        // Re-deriving the final row by applying `mimc_round` directly, one round
+        // constant at a time, should match the trace produced by `mimc_forward`.
+        let mut rebuilt = input.clone();
+        for round_constant in &round_constants {
+            rebuilt = mimc_round(&rebuilt, round_constant);
+        }
+        assert_eq!(*trace.last().unwrap(), rebuilt);
+    }
+
+    #[test]
+    fn mimc_round_constants_produces_requested_count_test() {
+        let field = PrimeFieldBig::new(b(5 * 2i128.pow(25) + 1));
+        let round_constants = mimc_round_constants(5, &field);
+        assert_eq!(5, round_constants.len());
+        for round_constant in round_constants {
+            assert_eq!(field, *round_constant.field);
+        }
+    }
 }
@@ -248,6 +248,33 @@ impl<
         acc
     }
 
+    // Substitute a single variable with a concrete value, leaving the rest symbolic.
+    // The substituted variable is dropped from the exponent vectors entirely, so the
+    // returned polynomial has one fewer variable than `self`, and variables after
+    // `variable_index` shift down by one to close the gap.
+    pub fn evaluate_partial(&self, variable_index: usize, value: U) -> Self {
+        let mut output_coefficients: MCoefficients<U> = HashMap::new();
+        for (k, v) in self.coefficients.iter() {
+            let new_coefficient = v.clone() * value.mod_pow_u64(k[variable_index]);
+            let mut new_key = k.clone();
+            new_key.remove(variable_index);
+
+            if output_coefficients.contains_key(&new_key) {
+                output_coefficients.insert(
+                    new_key.clone(),
+                    new_coefficient + output_coefficients[&new_key].clone(),
+                );
+            } else {
+                output_coefficients.insert(new_key, new_coefficient);
+            }
+        }
+
+        Self {
+            variable_count: self.variable_count - 1,
+            coefficients: output_coefficients,
+        }
+    }
+
     // Substitute the variables in a multivariate polynomial with univariate polynomials
     pub fn evaluate_symbolic(&self, point: &[Polynomial<U>]) -> Polynomial<U> {
         assert_eq!(
@@ -839,6 +866,24 @@ mod test_mpolynomials {
         assert_eq!(get_z(&_13), vars_3[2]);
     }
 
+    #[test]
+    fn difference_of_squares_factors_via_operators_test() {
+        let _13 = PrimeFieldBig::new(b(13));
+        let one = pfb(1, &_13);
+        let vars = MPolynomial::variables(2, one);
+        let x0 = vars[0].clone();
+        let x1 = vars[1].clone();
+
+        let lhs = (x0.clone() + x1.clone()) * (x0.clone() - x1.clone());
+        let rhs = x0.mod_pow(b(2), pfb(1, &_13)) - x1.mod_pow(b(2), pfb(1, &_13));
+        assert_eq!(rhs, lhs);
+
+        for (a, b_val) in [(0, 0), (1, 2), (5, 3), (12, 7)] {
+            let point = vec![pfb(a, &_13), pfb(b_val, &_13)];
+            assert_eq!(lhs.evaluate(&point), rhs.evaluate(&point));
+        }
+    }
+
     #[test]
     fn evaluate_symbolic_test() {
         let _13 = PrimeFieldBig::new(b(13));
@@ -909,6 +954,32 @@ mod test_mpolynomials {
         assert_eq!(expected_result, evaluated_pol_u)
     }
 
+    #[test]
+    fn evaluate_symbolic_composes_x0_times_x1_test() {
+        let _13 = PrimeFieldBig::new(b(13));
+        let zero = pfb(0, &_13);
+        let one = pfb(1, &_13);
+        let vars = MPolynomial::variables(2, one.clone());
+        let x0_times_x1 = vars[0].clone() * vars[1].clone();
+
+        // t
+        let t: Polynomial<PrimeFieldElementBig> =
+            Polynomial::from_constant(one.clone()).shift_coefficients(1, zero.clone());
+        // t + 1
+        let t_plus_one = Polynomial {
+            coefficients: vec![one.clone(), one.clone()],
+        };
+
+        // (x0*x1)(t, t+1) = t*(t+1) = t^2 + t
+        let expected = Polynomial {
+            coefficients: vec![zero, one.clone(), one],
+        };
+        assert_eq!(
+            expected,
+            x0_times_x1.evaluate_symbolic(&vec![t, t_plus_one])
+        );
+    }
+
     #[test]
     fn evaluate_symbolic_with_zeros_test() {
         let _13 = PrimeFieldBig::new(b(13));
@@ -976,6 +1047,26 @@ mod test_mpolynomials {
         );
     }
 
+    #[test]
+    fn evaluate_partial_test() {
+        let _13 = PrimeFieldBig::new(b(13));
+        let pol_m = get_x_plus_xz_minus_17y(&_13);
+        let x1_value = pfb(2, &_13);
+        let partially_evaluated = pol_m.evaluate_partial(1, x1_value.clone());
+
+        // Substituting x1 drops one variable, so the result takes points of length 2
+        // instead of 3.
+        assert_eq!(2, partially_evaluated.variable_count);
+        for (x0, x2) in [(0, 0), (1, 5), (6, 12), (12, 1)] {
+            let x0 = pfb(x0, &_13);
+            let x2 = pfb(x2, &_13);
+            assert_eq!(
+                pol_m.evaluate(&vec![x0.clone(), x1_value.clone(), x2.clone()]),
+                partially_evaluated.evaluate(&vec![x0, x2])
+            );
+        }
+    }
+
     #[test]
     fn lift_test() {
         let _13 = PrimeFieldBig::new(b(13));
@@ -58,7 +58,15 @@ pub fn ntt_recursive<
     result
 }
 
-pub fn ntt<T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + IdentityValues + Clone>(
+pub fn ntt<
+    T: Add<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + Div<Output = T>
+        + IdentityValues
+        + Clone
+        + New,
+>(
     x: &[T],
     omega: &T,
 ) -> Vec<T> {
@@ -83,7 +91,166 @@ pub fn ntt<T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + IdentityValu
         panic!("ntt needs primitive nth root of unity but order of omega does not match n");
     }
 
-    ntt_recursive(x, omega)
+    NttDomain::new(n, omega.clone()).forward(x)
+}
+
+/// The swaps performed by the bit-reversal permutation on a length-`n` slice, in
+/// application order. Depends only on `n`, so callers that run many transforms over
+/// the same domain (see `NttDomain`) can compute this once and replay it.
+fn bit_reversal_swaps(n: usize) -> Vec<(usize, usize)> {
+    let mut swaps = Vec::new();
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            swaps.push((i, j));
+        }
+    }
+    swaps
+}
+
+fn apply_bit_reversal<T>(x: &mut [T], swaps: &[(usize, usize)]) {
+    for &(i, j) in swaps {
+        x.swap(i, j);
+    }
+}
+
+/// The root of unity needed at each butterfly level is `omega^(n/len)`, for `len`
+/// doubling from 2 to `n`. Get there the same way `ntt_recursive` derives the root
+/// for each half: repeated squaring from the full-domain `omega` down, then read the
+/// levels off in the order the butterfly network needs them.
+fn level_roots<T: Mul<Output = T> + Clone>(n: usize, omega: &T) -> Vec<T> {
+    let mut roots = Vec::with_capacity(n.trailing_zeros() as usize);
+    let mut root = omega.clone();
+    let mut size = n;
+    while size >= 2 {
+        roots.push(root.clone());
+        root = root.clone() * root.clone();
+        size /= 2;
+    }
+    roots.reverse();
+    roots
+}
+
+fn butterfly<T>(x: &mut [T], level_roots: &[T])
+where
+    T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + IdentityValues + Clone,
+{
+    let n = x.len();
+    let mut len = 2usize;
+    let mut level = 0usize;
+    while len <= n {
+        let w_len = &level_roots[level];
+        let mut start = 0usize;
+        while start < n {
+            let mut w = w_len.ring_one();
+            for k in 0..len / 2 {
+                let u = x[start + k].clone();
+                let v = x[start + k + len / 2].clone() * w.clone();
+                x[start + k] = u.clone() + v.clone();
+                x[start + k + len / 2] = u + (-v);
+                w = w * w_len.clone();
+            }
+            start += len;
+        }
+        len <<= 1;
+        level += 1;
+    }
+}
+
+/// Iterative, in-place Cooley-Tukey NTT. Unlike `ntt_recursive`, this does not allocate
+/// a new `Vec` for every split; it permutes `x` in place via bit-reversal and then runs
+/// the butterfly network directly on it.
+pub fn ntt_in_place<T>(x: &mut [T], omega: &T)
+where
+    T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + IdentityValues + Clone,
+{
+    let n = x.len();
+    if n & (n - 1) != 0 {
+        panic!("ntt must operate on vector of length power of two");
+    }
+
+    apply_bit_reversal(x, &bit_reversal_swaps(n));
+    butterfly(x, &level_roots(n, omega));
+}
+
+/// A domain of size `n` with a fixed primitive `n`-th root of unity, set up once so
+/// that the bit-reversal permutation and twiddle-factor tables for both the forward
+/// and inverse transform don't need to be recomputed on every call. Intended for a
+/// prover that runs many transforms over the same evaluation domain.
+pub struct NttDomain<T> {
+    swaps: Vec<(usize, usize)>,
+    forward_roots: Vec<T>,
+    inverse_roots: Vec<T>,
+    size_as_field_element: T,
+}
+
+impl<T> NttDomain<T>
+where
+    T: Add<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + Div<Output = T>
+        + IdentityValues
+        + Clone
+        + New,
+{
+    pub fn new(n: usize, omega: T) -> Self {
+        if n & (n - 1) != 0 {
+            panic!("NttDomain size must be a power of two");
+        }
+
+        let omega_inv = omega.ring_one() / omega.clone();
+        Self {
+            swaps: bit_reversal_swaps(n),
+            forward_roots: level_roots(n, &omega),
+            inverse_roots: level_roots(n, &omega_inv),
+            size_as_field_element: omega.new_from_usize(n),
+        }
+    }
+
+    pub fn forward(&self, coeffs: &[T]) -> Vec<T> {
+        let mut result = coeffs.to_vec();
+        apply_bit_reversal(&mut result, &self.swaps);
+        butterfly(&mut result, &self.forward_roots);
+        result
+    }
+
+    pub fn inverse(&self, evals: &[T]) -> Vec<T> {
+        let mut result = evals.to_vec();
+        apply_bit_reversal(&mut result, &self.swaps);
+        butterfly(&mut result, &self.inverse_roots);
+        for value in result.iter_mut() {
+            *value = value.clone() / self.size_as_field_element.clone();
+        }
+        result
+    }
+}
+
+/// In-place inverse NTT built on top of `ntt_in_place`: run the butterfly network with
+/// the inverse root, then scale every entry by `1/n`, all without the allocations that
+/// `intt` incurs through `ntt`'s output vector and twiddle tables.
+pub fn intt_in_place<T>(x: &mut [T], omega: &T)
+where
+    T: Add<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + Div<Output = T>
+        + IdentityValues
+        + Clone
+        + New,
+{
+    let n_inv = omega.new_from_usize(x.len());
+    let omega_inv = omega.ring_one() / omega.to_owned();
+    ntt_in_place(x, &omega_inv);
+    for xi in x.iter_mut() {
+        *xi = xi.clone() / n_inv.clone();
+    }
 }
 
 pub fn intt<
@@ -109,6 +276,153 @@ pub fn intt<
         .collect()
 }
 
+fn pow_by_repeated_multiplication<T: Mul<Output = T> + IdentityValues + Clone>(
+    base: &T,
+    exponent: usize,
+) -> T {
+    let mut result = base.ring_one();
+    for _ in 0..exponent {
+        result = result * base.clone();
+    }
+    result
+}
+
+/// Direct O(n^2) DFT, used by `ntt_mixed_radix` for the length-`n1` transforms across
+/// each column, `n1` being one of the small radices (e.g. 2, 3, 5) in `factors`.
+fn small_dft<T: Add<Output = T> + Mul<Output = T> + IdentityValues + Clone>(
+    x: &[T],
+    omega: &T,
+) -> Vec<T> {
+    let n = x.len();
+    let mut output = Vec::with_capacity(n);
+    for k in 0..n {
+        let step = pow_by_repeated_multiplication(omega, k);
+        let mut omega_jk = omega.ring_one();
+        let mut acc = x[0].ring_zero();
+        for xj in x.iter() {
+            acc = acc + xj.clone() * omega_jk.clone();
+            omega_jk = omega_jk * step.clone();
+        }
+        output.push(acc);
+    }
+    output
+}
+
+/// Mixed-radix Cooley-Tukey NTT for sizes that aren't a power of two, e.g. 12 or 24.
+/// `factors` must multiply to `x.len()` and `omega` must be a primitive `x.len()`-th
+/// root of unity; each factor becomes the radix of one decomposition step (recursing
+/// over the remaining factors), bottoming out in a direct DFT once factors is empty.
+/// The classic power-of-two `ntt` is the special case where every factor is 2.
+pub fn ntt_mixed_radix<T: Add<Output = T> + Mul<Output = T> + IdentityValues + Clone>(
+    x: &[T],
+    omega: &T,
+    factors: &[usize],
+) -> Vec<T> {
+    let n = x.len();
+    if factors.is_empty() {
+        assert_eq!(1, n, "ran out of factors before reaching a length-1 transform");
+        return x.to_vec();
+    }
+
+    let n1 = factors[0];
+    let n2 = n / n1;
+    assert_eq!(0, n % n1, "factor {} does not divide length {}", n1, n);
+
+    // omega^n1 has order n2: the root the inner, length-n2 transforms need.
+    let omega_n2 = pow_by_repeated_multiplication(omega, n1);
+    let twiddled_rows: Vec<Vec<T>> = (0..n1)
+        .map(|n1_idx| {
+            // Decimation-in-time split: row n1_idx is the length-n2 strided
+            // subsequence x[n1_idx], x[n1_idx + n1], x[n1_idx + 2*n1], ...,
+            // not a contiguous block. The contiguous split computes a different
+            // (wrong) sum, since the twiddle factor below is indexed by n1_idx.
+            let row: Vec<T> = (0..n2).map(|n2_idx| x[n1_idx + n1 * n2_idx].clone()).collect();
+            let transformed_row = ntt_mixed_radix(&row, &omega_n2, &factors[1..]);
+
+            let twiddle_step = pow_by_repeated_multiplication(omega, n1_idx);
+            let mut twiddle = omega.ring_one();
+            transformed_row
+                .into_iter()
+                .map(|y| {
+                    let z = y * twiddle.clone();
+                    twiddle = twiddle.clone() * twiddle_step.clone();
+                    z
+                })
+                .collect()
+        })
+        .collect();
+
+    // omega^n2 has order n1: the root the final, length-n1 butterfly needs.
+    let omega_n1 = pow_by_repeated_multiplication(omega, n2);
+    let mut output = vec![x[0].ring_zero(); n];
+    for k1 in 0..n2 {
+        let column: Vec<T> = (0..n1).map(|n1_idx| twiddled_rows[n1_idx][k1].clone()).collect();
+        // Output index k1 + n2*m, not a sequential append: the two loop
+        // variables interleave with stride n2, they don't concatenate.
+        for (m, value) in small_dft(&column, &omega_n1).into_iter().enumerate() {
+            output[k1 + n2 * m] = value;
+        }
+    }
+
+    output
+}
+
+/// Inverse of `ntt_mixed_radix`: transform with `1/omega`, then scale every entry by
+/// `1/n`.
+pub fn intt_mixed_radix<
+    T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + IdentityValues + Clone + New,
+>(
+    x: &[T],
+    omega: &T,
+    factors: &[usize],
+) -> Vec<T> {
+    let n: T = omega.new_from_usize(x.len());
+    let omega_inv = omega.ring_one() / omega.to_owned();
+    ntt_mixed_radix(x, &omega_inv, factors)
+        .into_iter()
+        .map(|xi| xi / n.clone())
+        .collect()
+}
+
+/// Negacyclic forward NTT for convolution in `Z_q[x]/(x^n+1)`: twist the coefficients by
+/// powers of `psi`, a primitive `2n`-th root of unity, then run the standard NTT with
+/// `omega = psi^2`. Pointwise-multiplying two negacyclic transforms and inverting with
+/// `intt_negacyclic` gives the product reduced mod `x^n+1`, without an explicit reduction
+/// step.
+pub fn ntt_negacyclic<T>(x: &[T], psi: &T) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + Div<Output = T> + IdentityValues + Clone + New,
+{
+    let omega = psi.clone() * psi.clone();
+    let mut twisted = Vec::with_capacity(x.len());
+    let mut psi_power = psi.ring_one();
+    for xi in x.iter() {
+        twisted.push(xi.clone() * psi_power.clone());
+        psi_power = psi_power * psi.clone();
+    }
+
+    ntt(&twisted, &omega)
+}
+
+/// Inverse of `ntt_negacyclic`: run the standard `intt` with `omega = psi^2`, then untwist
+/// by the negative powers of `psi`.
+pub fn intt_negacyclic<T>(x: &[T], psi: &T) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + Div<Output = T> + IdentityValues + Clone + New,
+{
+    let omega = psi.clone() * psi.clone();
+    let untwisted = intt(x, &omega);
+    let psi_inv = psi.ring_one() / psi.clone();
+    let mut psi_inv_power = psi.ring_one();
+    let mut result = Vec::with_capacity(x.len());
+    for xi in untwisted.into_iter() {
+        result.push(xi * psi_inv_power.clone());
+        psi_inv_power = psi_inv_power * psi_inv.clone();
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod ntt_tests {
     use super::super::prime_field_element_big::{PrimeFieldBig, PrimeFieldElementBig};
@@ -166,6 +480,67 @@ mod ntt_tests {
         assert_eq!(expected_output, actual_output);
     }
 
+    #[test]
+    fn ntt_intt_in_place_matches_out_of_place_test() {
+        let field = pfb(5);
+        let generator = pfeb(2, &field);
+        let input = vec![
+            pfeb(1, &field),
+            pfeb(4, &field),
+            pfeb(0, &field),
+            pfeb(0, &field),
+        ];
+
+        let mut forward_in_place = input.clone();
+        ntt_in_place(&mut forward_in_place, &generator);
+        assert_eq!(ntt(&input, &generator), forward_in_place);
+
+        let mut round_trip = forward_in_place.clone();
+        intt_in_place(&mut round_trip, &generator);
+        assert_eq!(input, round_trip);
+        assert_eq!(intt(&forward_in_place, &generator), round_trip);
+    }
+
+    #[test]
+    fn ntt_in_place_matches_recursive_ntt_test() {
+        let prime = 167772161; // 2^25*5+1
+        let field: PrimeFieldBig = pfb(prime);
+        for &size in &[2, 4, 8, 1024, 2048] {
+            let input: Vec<PrimeFieldElementBig> = (0..size)
+                .map(|_| pfeb(rand::random::<u32>() as i128 % prime, &field))
+                .collect();
+            let (root, _): (Option<PrimeFieldElementBig>, Vec<BigInt>) =
+                field.get_primitive_root_of_unity(size);
+            let root = root.unwrap();
+
+            let recursive_output = ntt_recursive(&input, &root);
+            let mut in_place_output = input.clone();
+            ntt_in_place(&mut in_place_output, &root);
+            assert_eq!(recursive_output, in_place_output);
+            assert_eq!(recursive_output, ntt(&input, &root));
+        }
+    }
+
+    #[test]
+    fn ntt_domain_matches_ntt_and_is_stable_across_reuse_test() {
+        let prime = 167772161; // 2^25*5+1
+        let field: PrimeFieldBig = pfb(prime);
+        let size = 16;
+        let (root, _): (Option<PrimeFieldElementBig>, Vec<BigInt>) =
+            field.get_primitive_root_of_unity(size);
+        let root = root.unwrap();
+        let domain = NttDomain::new(size as usize, root.clone());
+
+        for _ in 0..10 {
+            let input: Vec<PrimeFieldElementBig> = (0..size)
+                .map(|_| pfeb(rand::random::<u32>() as i128 % prime, &field))
+                .collect();
+            let expected = ntt(&input, &root);
+            assert_eq!(expected, domain.forward(&input));
+            assert_eq!(input, domain.inverse(&domain.forward(&input)));
+        }
+    }
+
     #[test]
     fn fast_polynomial_functions_property_based_test() {
         let prime = 167772161; // 2^25*5+1
@@ -195,4 +570,80 @@ mod ntt_tests {
             }
         }
     }
+
+    #[test]
+    fn negacyclic_ntt_matches_reduced_schoolbook_multiplication_test() {
+        let prime = 167772161; // 2^25*5+1
+        let field: PrimeFieldBig = pfb(prime);
+        let n = 4;
+        let (psi_option, _): (Option<PrimeFieldElementBig>, Vec<BigInt>) =
+            field.get_primitive_root_of_unity(2 * n);
+        let psi = psi_option.unwrap();
+
+        let a: Vec<PrimeFieldElementBig> = (0..n)
+            .map(|_| pfeb(rand::random::<u32>() as i128 % prime, &field))
+            .collect();
+        let b: Vec<PrimeFieldElementBig> = (0..n)
+            .map(|_| pfeb(rand::random::<u32>() as i128 % prime, &field))
+            .collect();
+
+        let a_hat = ntt_negacyclic(&a, &psi);
+        let b_hat = ntt_negacyclic(&b, &psi);
+        let c_hat: Vec<PrimeFieldElementBig> = a_hat
+            .iter()
+            .zip(b_hat.iter())
+            .map(|(x, y)| x.clone() * y.clone())
+            .collect();
+        let actual = intt_negacyclic(&c_hat, &psi);
+
+        // Schoolbook multiply, then fold x^(n+k) down to -x^k, since x^n = -1 in
+        // Z_q[x]/(x^n+1).
+        let n = n as usize;
+        let mut expected = vec![pfeb(0, &field); n];
+        for i in 0..n {
+            for j in 0..n {
+                let product = a[i].clone() * b[j].clone();
+                if i + j < n {
+                    expected[i + j] = expected[i + j].clone() + product;
+                } else {
+                    expected[i + j - n] = expected[i + j - n].clone() - product;
+                }
+            }
+        }
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ntt_mixed_radix_round_trips_through_inverse_test() {
+        // 13 - 1 = 12 = 4 * 3, so this field has a primitive 12th root of unity even
+        // though 12 is not a power of two.
+        let prime = 13;
+        let field: PrimeFieldBig = pfb(prime);
+        let size = 12;
+        let (root_option, _): (Option<PrimeFieldElementBig>, Vec<BigInt>) =
+            field.get_primitive_root_of_unity(size);
+        let root = root_option.unwrap();
+
+        let input: Vec<PrimeFieldElementBig> = (0..size)
+            .map(|i| pfeb(i % prime, &field))
+            .collect();
+
+        for factors in [vec![4, 3], vec![2, 2, 3], vec![3, 4], vec![2, 3, 2]] {
+            let transformed = ntt_mixed_radix(&input, &root, &factors);
+            assert_eq!(transformed, ntt_recursive_for_any_size(&input, &root));
+
+            let round_trip = intt_mixed_radix(&transformed, &root, &factors);
+            assert_eq!(input, round_trip);
+        }
+    }
+
+    /// Reference implementation used only to cross-check `ntt_mixed_radix`: the
+    /// textbook O(n^2) DFT.
+    fn ntt_recursive_for_any_size<T: Add<Output = T> + Mul<Output = T> + IdentityValues + Clone>(
+        x: &[T],
+        omega: &T,
+    ) -> Vec<T> {
+        small_dft(x, omega)
+    }
 }
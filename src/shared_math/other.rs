@@ -25,6 +25,94 @@ pub fn log_2_ceil(x: u64) -> u64 {
 
 // pub fn lagrange_interpolation_2
 
+fn mod_mul_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+fn mod_pow_u64(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul_u64(result, base, modulus);
+        }
+        exponent >>= 1;
+        base = mod_mul_u64(base, base, modulus);
+    }
+    result
+}
+
+// Deterministic Miller-Rabin primality test. The witness set below is known to
+// correctly classify every integer below 318,665,857,834,031,151,167,461
+// (~2^78), which covers the entire u64 range.
+pub fn is_prime(n: u64) -> bool {
+    const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in SMALL_PRIMES.iter() {
+        if n == p {
+            return true;
+        }
+        if n % p == 0 {
+            return false;
+        }
+    }
+
+    let mut d = n - 1;
+    let mut r = 0u32;
+    while d % 2 == 0 {
+        d /= 2;
+        r += 1;
+    }
+
+    'witness_loop: for &a in SMALL_PRIMES.iter() {
+        let mut x = mod_pow_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 0..r - 1 {
+            x = mod_mul_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness_loop;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+// The smallest prime strictly greater than `n`.
+pub fn next_prime(n: u64) -> u64 {
+    let mut candidate = n + 1;
+    while !is_prime(candidate) {
+        candidate += 1;
+    }
+    candidate
+}
+
+// Extended Euclidean algorithm. Returns `(gcd, x, y)` such that `a*x + b*y == gcd`.
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if a == 0 {
+        (b, 0, 1)
+    } else {
+        let (gcd, x1, y1) = egcd(b % a, a);
+        (gcd, y1 - (b / a) * x1, x1)
+    }
+}
+
+// The inverse of `a` modulo `m`, or `None` if `a` and `m` are not coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (gcd, x, _) = egcd(a, m);
+    if gcd != 1 {
+        None
+    } else {
+        Some(((x % m) + m) % m)
+    }
+}
+
 #[cfg(test)]
 mod test_other {
     use super::*;
@@ -55,4 +143,45 @@ mod test_other {
         assert_eq!(41, log_2_ceil(2u64.pow(40) + 1));
         assert_eq!(41, log_2_ceil(2u64.pow(40) + 456456));
     }
+
+    #[test]
+    fn is_prime_test() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+        assert!(is_prime(65537));
+        assert!(!is_prime(65536));
+        assert!(!is_prime(1_000_000));
+        assert!(is_prime(999_999_937));
+    }
+
+    #[test]
+    fn next_prime_test() {
+        assert_eq!(101, next_prime(100));
+        assert_eq!(3, next_prime(2));
+        assert_eq!(5, next_prime(4));
+        assert_eq!(2, next_prime(1));
+    }
+
+    #[test]
+    fn egcd_test() {
+        let (gcd, x, y) = egcd(35, 15);
+        assert_eq!(5, gcd);
+        assert_eq!(5, 35 * x + 15 * y);
+
+        let (gcd, x, y) = egcd(3, 26);
+        assert_eq!(1, gcd);
+        assert_eq!(1, 3 * x + 26 * y);
+    }
+
+    #[test]
+    fn mod_inverse_test() {
+        assert_eq!(Some(9), mod_inverse(3, 26));
+        assert_eq!(Some(1), mod_inverse(1, 26));
+
+        // 4 and 8 are not coprime, so no inverse exists
+        assert_eq!(None, mod_inverse(4, 8));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::shared_math::ntt::{intt, ntt};
+use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
 use crate::shared_math::traits::IdentityValues;
-use crate::utils::has_unique_elements;
+use crate::utils::{generate_random_numbers_seeded, has_unique_elements};
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
 use num_bigint::BigInt;
@@ -170,6 +171,18 @@ impl<
         }
     }
 
+    /// Read-only access to the coefficient vector, lowest-degree term first.
+    pub fn coefficients(&self) -> &[U] {
+        &self.coefficients
+    }
+
+    /// Alias for [`Polynomial::normalize`]: drops trailing (highest-degree) zero
+    /// coefficients so `degree()` reflects the polynomial's true degree, e.g. after a
+    /// subtraction cancels the leading term.
+    pub fn trim(&mut self) {
+        self.normalize();
+    }
+
     pub fn ring_zero() -> Self {
         Self {
             coefficients: vec![],
@@ -200,6 +213,25 @@ impl<
         acc
     }
 
+    // Formal derivative: d/dx sum_i c_i x^i = sum_{i>=1} i * c_i x^(i-1). The integer
+    // multiplier `i` is built up by repeated addition of the ring's `one` so this works
+    // over any ring/field, with the multiplier implicitly reduced mod the field's
+    // characteristic.
+    pub fn derivative(&self) -> Self {
+        if self.coefficients.len() <= 1 {
+            return Self::ring_zero();
+        }
+
+        let mut coefficients = Vec::with_capacity(self.coefficients.len() - 1);
+        let mut multiplier = self.coefficients[0].ring_one();
+        for coefficient in self.coefficients.iter().skip(1) {
+            coefficients.push(coefficient.to_owned() * multiplier.clone());
+            multiplier = multiplier.clone() + self.coefficients[0].ring_one();
+        }
+
+        Self { coefficients }
+    }
+
     // Return the polynomial which corresponds to the transformation `x -> alpha * x`
     // x should probably be called alpha below
     pub fn scale(&self, x: &U) -> Self {
@@ -264,6 +296,35 @@ impl<
         true
     }
 
+    /// Generalization of `are_colinear` (which is `lie_on_degree_n(points, 1)`):
+    /// interpolate a degree-`n` polynomial through the first `n + 1` points and check
+    /// that every remaining point lies on it. Useful for verifying FRI foldings with a
+    /// folding factor greater than two, where colinearity alone isn't enough.
+    pub fn lie_on_degree_n(points: &[(U, U)], n: usize) -> bool {
+        if points.len() < n + 2 {
+            println!(
+                "Too few points received. Got: {} points, need at least {}",
+                points.len(),
+                n + 2
+            );
+            return false;
+        }
+
+        if !has_unique_elements(points.iter().map(|p| p.0.clone())) {
+            println!("Non-unique element spotted Got: {:?}", points);
+            return false;
+        }
+
+        let interpolant = Self::slow_lagrange_interpolation(&points[..=n]);
+        for point in points.iter().skip(n + 1) {
+            if interpolant.evaluate(&point.0) != point.1 {
+                return false;
+            }
+        }
+
+        true
+    }
+
     // Calculates a reversed representation of the coefficients of
     // prod_{i=0}^{N}((x- q_i))
     fn prod_helper<T: IdentityValues + Sub<Output = T> + Mul<Output = T> + Clone>(
@@ -299,6 +360,14 @@ impl<
         Polynomial { coefficients }
     }
 
+    /// Build the vanishing polynomial `prod (x - r_i)` for the given roots. This is an
+    /// alias for `get_polynomial_with_roots` kept around for callers (e.g. FRI domain
+    /// zerofiers) that think of the construction in terms of its roots rather than its
+    /// use as a generic "polynomial with roots" builder.
+    pub fn from_roots(roots: &[U]) -> Self {
+        Self::get_polynomial_with_roots(roots)
+    }
+
     fn slow_lagrange_interpolation_internal(xs: &[U], ys: &[U]) -> Self {
         assert_eq!(
             xs.len(),
@@ -389,6 +458,129 @@ impl<
     }
 }
 
+/// Precomputes the product polynomial and per-node barycentric weights for a fixed
+/// set of x-coordinates, so that interpolating repeatedly over the same domain (e.g.
+/// the same FRI domain, round after round) skips the domain-only setup work that
+/// `Polynomial::slow_lagrange_interpolation` otherwise redoes on every call.
+pub struct LagrangeInterpolator<
+    U: Add<Output = U>
+        + Div<Output = U>
+        + Mul<Output = U>
+        + Rem
+        + Sub<Output = U>
+        + IdentityValues
+        + Clone
+        + Display
+        + Debug
+        + PartialEq
+        + Eq
+        + Hash,
+> {
+    xs: Vec<U>,
+    // weights[j] = 1 / prod_{k != j} (xs[j] - xs[k])
+    weights: Vec<U>,
+    big_pol: Polynomial<U>,
+}
+
+impl<
+        U: Add<Output = U>
+            + Div<Output = U>
+            + Mul<Output = U>
+            + Rem
+            + Sub<Output = U>
+            + IdentityValues
+            + Clone
+            + Display
+            + Debug
+            + PartialEq
+            + Eq
+            + Hash,
+    > LagrangeInterpolator<U>
+{
+    pub fn new(xs: &[U]) -> Self {
+        if !has_unique_elements(xs.iter()) {
+            panic!("Repeated x values received. Got: {:?}", xs);
+        }
+
+        let one = xs[0].ring_one();
+        let weights: Vec<U> = xs
+            .iter()
+            .map(|xj| {
+                let divisor = xs.iter().filter(|xk| *xk != xj).fold(one.clone(), |acc, xk| {
+                    acc * (xj.clone() - xk.clone())
+                });
+                one.clone() / divisor
+            })
+            .collect();
+
+        let mut big_pol_coefficients = Polynomial::<U>::prod_helper(xs);
+        big_pol_coefficients.reverse();
+
+        Self {
+            xs: xs.to_vec(),
+            weights,
+            big_pol: Polynomial {
+                coefficients: big_pol_coefficients,
+            },
+        }
+    }
+
+    /// Interpolate the polynomial through `(xs[i], ys[i])` for every `i`, for the `xs`
+    /// this interpolator was built with.
+    pub fn interpolate(&self, ys: &[U]) -> Polynomial<U> {
+        assert_eq!(
+            self.xs.len(),
+            ys.len(),
+            "x and y values must have the same length"
+        );
+
+        let zero = self.xs[0].ring_zero();
+        let one = self.xs[0].ring_one();
+        let mut coefficients: Vec<U> = vec![zero.clone(); self.xs.len()];
+        for ((x, y), weight) in self.xs.iter().zip(ys.iter()).zip(self.weights.iter()) {
+            let my_pol = Polynomial {
+                coefficients: vec![zero.clone() - x.clone(), one.clone()],
+            };
+            let quotient = self.big_pol.clone() / my_pol;
+
+            let scale = y.clone() * weight.clone();
+            for (i, coeff) in quotient.coefficients.into_iter().enumerate() {
+                coefficients[i] = coefficients[i].clone() + coeff * scale.clone();
+            }
+        }
+
+        Polynomial { coefficients }
+    }
+
+    /// Evaluate the interpolant through `(xs[i], ys[i])` at `point`, without building
+    /// the full polynomial, via the barycentric formula
+    /// `L(point) = (sum_j w_j / (point - x_j) * y_j) / (sum_j w_j / (point - x_j))`.
+    pub fn evaluate_at(&self, ys: &[U], point: &U) -> U {
+        assert_eq!(
+            self.xs.len(),
+            ys.len(),
+            "x and y values must have the same length"
+        );
+
+        for (x, y) in self.xs.iter().zip(ys.iter()) {
+            if x == point {
+                return y.clone();
+            }
+        }
+
+        let zero = self.xs[0].ring_zero();
+        let mut numerator = zero.clone();
+        let mut denominator = zero;
+        for ((x, y), weight) in self.xs.iter().zip(ys.iter()).zip(self.weights.iter()) {
+            let term = weight.clone() / (point.clone() - x.clone());
+            numerator = numerator + term.clone() * y.clone();
+            denominator = denominator + term;
+        }
+
+        numerator / denominator
+    }
+}
+
 impl<
         U: Add<Output = U>
             + Div<Output = U>
@@ -594,6 +786,15 @@ impl<
         ntt(&coefficients, generator)
     }
 
+    /// Evaluate over the domain `{root^0, root^1, ..., root^(domain_size - 1)}` via NTT,
+    /// zero-padding the coefficients up to `domain_size` first. `root` must be a principal
+    /// `domain_size`-th root of unity.
+    pub fn evaluate_domain_ntt(&self, root: &U, domain_size: usize) -> Vec<U> {
+        let mut coefficients = self.coefficients.clone();
+        coefficients.append(&mut vec![root.ring_zero(); domain_size - coefficients.len()]);
+        ntt(&coefficients, root)
+    }
+
     /// Divide two polynomials under the homomorphism of evaluation for a N^2 -> N*log(N) speedup
     /// Since we often want to use this fast division for numerators and divisors that evaluate
     /// to zero in their domain, we do the division with an offset from the polynomials' original
@@ -744,6 +945,33 @@ impl<
         acc
     }
 
+    /// Compute `self^exp mod modulus` via repeated squaring, reducing by `modulus` after
+    /// each multiplication so the intermediate degree stays bounded by `modulus`'s degree
+    /// instead of growing to `exp * self.degree()`. Useful for zerofier-style exponentiation
+    /// in STARK constraint polynomials.
+    pub fn pow_mod(&self, exp: u64, modulus: &Self) -> Self {
+        let one = modulus.coefficients.last().unwrap().ring_one();
+        if exp == 0 {
+            return Self::from_constant(one).divide(modulus.clone()).1;
+        }
+
+        if self.is_zero() {
+            return Self::ring_zero();
+        }
+
+        let mut acc = Self::from_constant(one);
+        let bit_length = 64 - exp.leading_zeros();
+        for i in 0..bit_length {
+            acc = acc.clone().multiply(acc.clone()).divide(modulus.clone()).1;
+            let set = exp & (1u64 << (bit_length - 1 - i)) != 0;
+            if set {
+                acc = acc.multiply(self.clone()).divide(modulus.clone()).1;
+            }
+        }
+
+        acc
+    }
+
     // Multiply a polynomial with x^power
     pub fn shift_coefficients(&self, power: usize, zero: U) -> Self {
         if !zero.is_zero() {
@@ -827,6 +1055,31 @@ impl<
 
         (quotient_pol, remainder)
     }
+
+    // Scale the polynomial so its leading coefficient becomes one
+    fn normalize_to_monic(&self) -> Self {
+        let degree = self.degree();
+        if degree < 0 {
+            return self.clone();
+        }
+
+        let lc = self.coefficients[degree as usize].clone();
+        self.scalar_mul(lc.ring_one() / lc)
+    }
+
+    /// Greatest common divisor of two polynomials over a field, computed via the
+    /// Euclidean algorithm on top of `divide`. The result is normalized to be monic.
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        if b.is_zero() {
+            return a.normalize_to_monic();
+        }
+        if a.is_zero() {
+            return b.normalize_to_monic();
+        }
+
+        let (_, remainder) = a.divide(b.clone());
+        Self::gcd(b, &remainder)
+    }
 }
 
 impl<
@@ -965,6 +1218,25 @@ impl<
     }
 }
 
+impl<'a> Polynomial<PrimeFieldElement<'a>> {
+    /// Generate a random polynomial of exactly the given degree (i.e. with a nonzero
+    /// leading coefficient) over `field`, reproducible from `seed`. Intended for fuzzing
+    /// FRI and other protocols built on `Polynomial`, pairing with
+    /// `generate_random_numbers_seeded`.
+    pub fn random(degree: usize, field: &'a PrimeField, seed: u64) -> Self {
+        let raw_coefficients = generate_random_numbers_seeded(degree + 1, field.q, seed);
+        let mut coefficients: Vec<PrimeFieldElement<'a>> = raw_coefficients
+            .into_iter()
+            .map(|value| PrimeFieldElement::new(value, field))
+            .collect();
+        if coefficients[degree].is_zero() {
+            coefficients[degree] = PrimeFieldElement::new(1, field);
+        }
+
+        Self { coefficients }
+    }
+}
+
 impl<
         U: Add<Output = U>
             + Div<Output = U>
@@ -1259,6 +1531,75 @@ mod test_polynomials {
         );
     }
 
+    #[test]
+    fn coefficients_accessor_test() {
+        let _71 = PrimeField::new(71);
+        let _1_71 = PrimeFieldElement::new(1, &_71);
+        let _2_71 = PrimeFieldElement::new(2, &_71);
+        let a = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![_1_71, _2_71],
+        };
+        assert_eq!(&[_1_71, _2_71], a.coefficients());
+    }
+
+    #[test]
+    fn trim_is_alias_for_normalize_test() {
+        let _71 = PrimeField::new(71);
+        let _0_71 = PrimeFieldElement::new(0, &_71);
+        let _1_71 = PrimeFieldElement::new(1, &_71);
+        let mut a = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![_1_71, _0_71, _0_71],
+        };
+        a.trim();
+        assert_eq!(
+            Polynomial::<PrimeFieldElement> {
+                coefficients: vec![_1_71],
+            },
+            a
+        );
+    }
+
+    #[test]
+    fn degree_is_correct_after_subtracting_cancelling_top_term_test() {
+        let _71 = PrimeField::new(71);
+        let _1_71 = PrimeFieldElement::new(1, &_71);
+        let _2_71 = PrimeFieldElement::new(2, &_71);
+        let _5_71 = PrimeFieldElement::new(5, &_71);
+        let lhs = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![_1_71, _2_71, _5_71],
+        };
+        let rhs = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![_2_71, _1_71, _5_71],
+        };
+        let mut difference = lhs - rhs;
+
+        // The top term cancels, so the vector still holds a zero in the highest slot until
+        // it's trimmed, but `degree()` must already see through that.
+        assert_eq!(1, difference.degree());
+
+        difference.trim();
+        assert_eq!(1, difference.degree());
+        assert_eq!(
+            Polynomial::<PrimeFieldElement> {
+                coefficients: vec![_1_71 - _2_71, _2_71 - _1_71],
+            },
+            difference
+        );
+    }
+
+    #[test]
+    fn random_has_requested_degree_and_is_seed_reproducible_test() {
+        let field = PrimeField::new(7919);
+        let a = Polynomial::<PrimeFieldElement>::random(10, &field, 42);
+        assert_eq!(10, a.degree());
+
+        let b = Polynomial::<PrimeFieldElement>::random(10, &field, 42);
+        assert_eq!(a, b);
+
+        let c = Polynomial::<PrimeFieldElement>::random(10, &field, 43);
+        assert_ne!(a, c);
+    }
+
     #[test]
     fn get_polynomial_with_roots_test() {
         let field = PrimeField::new(31);
@@ -1287,6 +1628,119 @@ mod test_polynomials {
         );
     }
 
+    #[test]
+    fn from_roots_test() {
+        let field = PrimeField::new(31);
+        let roots = [pf(1, &field), pf(2, &field), pf(3, &field)];
+        let zerofier = Polynomial::from_roots(&roots);
+        assert_eq!(zerofier, Polynomial::get_polynomial_with_roots(&roots));
+        for root in roots.iter() {
+            assert!(zerofier.evaluate(root).is_zero());
+        }
+        for non_root in [pf(0, &field), pf(4, &field), pf(30, &field)].iter() {
+            assert!(!zerofier.evaluate(non_root).is_zero());
+        }
+    }
+
+    #[test]
+    fn evaluate_via_horner_test() {
+        let field = PrimeField::new(31);
+
+        // p(x) = 2x^2 + 3x + 5
+        let poly = Polynomial {
+            coefficients: vec![pf(5, &field), pf(3, &field), pf(2, &field)],
+        };
+        for x in 0..10 {
+            let x_elem = pf(x, &field);
+            let expected = pf(2 * x * x + 3 * x + 5, &field);
+            assert_eq!(expected, poly.evaluate(&x_elem));
+        }
+
+        let zero: Polynomial<PrimeFieldElement> = Polynomial::ring_zero();
+        assert!(zero.evaluate(&pf(17, &field)).is_zero());
+    }
+
+    #[test]
+    fn scalar_mul_distributes_over_addition_test() {
+        let field = PrimeField::new(31);
+        let scalar = pf(7, &field);
+
+        let a = Polynomial {
+            coefficients: vec![pf(1, &field), pf(2, &field)],
+        };
+        let b = Polynomial {
+            coefficients: vec![pf(3, &field), pf(0, &field), pf(4, &field)],
+        };
+
+        let lhs = (a.clone() + b.clone()).scalar_mul(scalar);
+        let rhs = a.scalar_mul(scalar) + b.scalar_mul(scalar);
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn display_test() {
+        let field = PrimeField::new(31);
+        let poly = Polynomial {
+            coefficients: vec![
+                pf(6, &field),
+                pf(0, &field),
+                pf(2, &field),
+                pf(5, &field),
+            ],
+        };
+        assert_eq!("5x^3 + 2x^2 + 6", poly.to_string());
+
+        let zero: Polynomial<PrimeFieldElement> = Polynomial::ring_zero();
+        assert_eq!("0", zero.to_string());
+    }
+
+    #[test]
+    fn gcd_test() {
+        let field = PrimeField::new(31);
+
+        // gcd(x^2 - 1, x - 1) = x - 1
+        let a = Polynomial {
+            coefficients: vec![pf(30, &field), pf(0, &field), pf(1, &field)],
+        };
+        let b = Polynomial {
+            coefficients: vec![pf(30, &field), pf(1, &field)],
+        };
+        assert_eq!(b, Polynomial::gcd(&a, &b));
+
+        // gcd(p, 0) = p, normalized to monic
+        let zero: Polynomial<PrimeFieldElement> = Polynomial::ring_zero();
+        assert_eq!(b, Polynomial::gcd(&b, &zero));
+        assert_eq!(b, Polynomial::gcd(&zero, &b));
+    }
+
+    #[test]
+    fn derivative_test() {
+        let field = PrimeField::new(31);
+
+        // d/dx(x^3 + 2x^2 + 5) = 3x^2 + 4x
+        let poly = Polynomial {
+            coefficients: vec![
+                pf(5, &field),
+                pf(0, &field),
+                pf(2, &field),
+                pf(1, &field),
+            ],
+        };
+        let expected = Polynomial {
+            coefficients: vec![pf(0, &field), pf(4, &field), pf(3, &field)],
+        };
+        assert_eq!(expected, poly.derivative());
+
+        // The derivative of a constant is the zero polynomial
+        let constant = Polynomial {
+            coefficients: vec![pf(17, &field)],
+        };
+        assert!(constant.derivative().is_zero());
+
+        let zero_poly: Polynomial<PrimeFieldElement> = Polynomial::ring_zero();
+        assert!(zero_poly.derivative().is_zero());
+    }
+
     #[test]
     fn slow_lagrange_interpolation_test() {
         let field = PrimeField::new(7);
@@ -1330,6 +1784,33 @@ mod test_polynomials {
         assert_eq!(expected_result, interpolation_result);
     }
 
+    #[test]
+    fn lagrange_interpolator_matches_slow_lagrange_interpolation_test() {
+        let field = PrimeField::new(7);
+        let xs = [pf(0, &field), pf(1, &field), pf(2, &field), pf(3, &field)];
+        let interpolator = LagrangeInterpolator::new(&xs);
+
+        for ys in [
+            [pf(6, &field), pf(6, &field), pf(2, &field), pf(1, &field)],
+            [pf(1, &field), pf(0, &field), pf(3, &field), pf(5, &field)],
+        ] {
+            let points: Vec<(PrimeFieldElement, PrimeFieldElement)> =
+                xs.iter().cloned().zip(ys.iter().cloned()).collect();
+            let expected = Polynomial::slow_lagrange_interpolation(&points);
+            let actual = interpolator.interpolate(&ys);
+            assert_eq!(expected, actual);
+
+            for (x, y) in points.iter() {
+                assert_eq!(*y, interpolator.evaluate_at(&ys, x));
+            }
+            let off_domain_point = pf(5, &field);
+            assert_eq!(
+                expected.evaluate(&off_domain_point),
+                interpolator.evaluate_at(&ys, &off_domain_point)
+            );
+        }
+    }
+
     #[test]
     fn slow_lagrange_interpolation_test_big() {
         let field = PrimeFieldBig::new(b(7));
@@ -1546,6 +2027,32 @@ mod test_polynomials {
         ]));
     }
 
+    #[test]
+    fn polynomial_lie_on_degree_n_test() {
+        let field = PrimeField::new(101);
+
+        // Points on y = x^2
+        let points = [
+            (pf(1, &field), pf(1, &field)),
+            (pf(2, &field), pf(4, &field)),
+            (pf(3, &field), pf(9, &field)),
+            (pf(4, &field), pf(16, &field)),
+        ];
+        assert!(Polynomial::lie_on_degree_n(&points, 2));
+        assert!(!Polynomial::lie_on_degree_n(&points, 1));
+
+        // are_colinear is lie_on_degree_n(points, 1)
+        let colinear_points = [
+            (pf(1, &field), pf(1, &field)),
+            (pf(2, &field), pf(2, &field)),
+            (pf(3, &field), pf(3, &field)),
+        ];
+        assert!(Polynomial::lie_on_degree_n(&colinear_points, 1));
+
+        // Too few points for the requested degree
+        assert!(!Polynomial::lie_on_degree_n(&colinear_points, 2));
+    }
+
     #[test]
     fn polynomial_are_colinear_test_big() {
         let field = PrimeFieldBig::new(b(5));
@@ -1753,6 +2260,36 @@ mod test_polynomials {
         assert_eq!(parabola_squared, parabola.mod_pow(2.into(), one));
     }
 
+    #[test]
+    fn pow_mod_test() {
+        let _71 = PrimeField::new(71);
+        let zero = PrimeFieldElement::new(0, &_71);
+        let one = PrimeFieldElement::new(1, &_71);
+        let minus_one = PrimeFieldElement::new(-1, &_71);
+
+        // x^8 mod (x^3 - 1) = x^2, since x^3 = 1 (mod x^3 - 1)
+        let x = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![zero, one],
+        };
+        let x_squared = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![zero, zero, one],
+        };
+        let modulus = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![minus_one, zero, zero, one],
+        };
+        assert_eq!(x_squared, x.pow_mod(8, &modulus));
+
+        // Exponent 0 is always the constant 1 (reduced mod the modulus).
+        let one_pol = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![one],
+        };
+        assert_eq!(one_pol, x.pow_mod(0, &modulus));
+
+        // The zero polynomial stays zero for any positive exponent.
+        let zero_pol: Polynomial<PrimeFieldElement> = Polynomial::ring_zero();
+        assert_eq!(zero_pol, zero_pol.pow_mod(5, &modulus));
+    }
+
     #[test]
     fn polynomial_arithmetic_property_based_test() {
         let prime_modulus = 71;
@@ -2030,6 +2567,58 @@ mod test_polynomials {
         );
     }
 
+    #[test]
+    fn divide_test() {
+        let field = PrimeField::new(71);
+
+        // x^3 - 1 divided by x - 1 gives x^2 + x + 1 with zero remainder
+        let x_cubed_minus_one = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![
+                pf(-1, &field),
+                pf(0, &field),
+                pf(0, &field),
+                pf(1, &field),
+            ],
+        };
+        let x_minus_one = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![pf(-1, &field), pf(1, &field)],
+        };
+        let (quotient, remainder) = x_cubed_minus_one.divide(x_minus_one.clone());
+        assert_eq!(
+            Polynomial::<PrimeFieldElement> {
+                coefficients: vec![pf(1, &field), pf(1, &field), pf(1, &field)],
+            },
+            quotient
+        );
+        assert!(remainder.is_zero());
+        assert_eq!(
+            x_cubed_minus_one,
+            quotient.clone() * x_minus_one.clone() + remainder.clone()
+        );
+
+        // x^3 + 5 divided by x - 1 leaves a nonzero remainder
+        let x_cubed_plus_five = Polynomial::<PrimeFieldElement> {
+            coefficients: vec![
+                pf(5, &field),
+                pf(0, &field),
+                pf(0, &field),
+                pf(1, &field),
+            ],
+        };
+        let (quotient, remainder) = x_cubed_plus_five.divide(x_minus_one.clone());
+        assert!(remainder.degree() < x_minus_one.degree());
+        assert_eq!(
+            x_cubed_plus_five,
+            quotient * x_minus_one + remainder.clone()
+        );
+        assert_eq!(
+            Polynomial::<PrimeFieldElement> {
+                coefficients: vec![pf(6, &field)],
+            },
+            remainder
+        );
+    }
+
     #[test]
     fn polynomial_arithmetic_test_linear_combination() {
         let field = PrimeFieldBig::new(b(167772161));
@@ -2206,6 +2795,30 @@ mod test_polynomials {
         );
     }
 
+    #[test]
+    fn fast_multiply_degree_500_test() {
+        let field = PrimeFieldBig::new(65537.into());
+        let root_order = 2048; // a power of two above the product's degree, 1000; `fast_multiply` reduces it to 1024
+        let primitive_root = field.get_primitive_root_of_unity(root_order).0.unwrap();
+
+        let a: Polynomial<PrimeFieldElementBig> = Polynomial {
+            coefficients: generate_random_numbers(501, 65537)
+                .iter()
+                .map(|x| pfb(*x, &field))
+                .collect(),
+        };
+        let b: Polynomial<PrimeFieldElementBig> = Polynomial {
+            coefficients: generate_random_numbers(501, 65537)
+                .iter()
+                .map(|x| pfb(*x, &field))
+                .collect(),
+        };
+
+        let c_fast = Polynomial::fast_multiply(&a, &b, &primitive_root, root_order as usize);
+        let c_normal = a * b;
+        assert_eq!(c_normal, c_fast);
+    }
+
     #[test]
     fn fast_zerofier_test() {
         let _17 = PrimeField::new(17);
@@ -2274,6 +2887,29 @@ mod test_polynomials {
         assert_eq!(expected_12, actual[1]);
     }
 
+    #[test]
+    fn fast_evaluate_on_many_random_points_test() {
+        let field = PrimeFieldBig::new(65537.into());
+        let root_order = 256;
+        let primitive_root = field.get_primitive_root_of_unity(root_order).0.unwrap();
+
+        let poly: Polynomial<PrimeFieldElementBig> = Polynomial {
+            coefficients: generate_random_numbers(20, 65537)
+                .iter()
+                .map(|x| pfb(*x, &field))
+                .collect(),
+        };
+        let domain: Vec<PrimeFieldElementBig> = generate_random_numbers(100, 65537)
+            .iter()
+            .map(|x| pfb(*x, &field))
+            .collect();
+
+        let fast_results = poly.fast_evaluate(&domain, &primitive_root, root_order as usize);
+        let naive_results: Vec<PrimeFieldElementBig> =
+            domain.iter().map(|x| poly.evaluate(x)).collect();
+        assert_eq!(naive_results, fast_results);
+    }
+
     #[test]
     fn fast_interpolate_test() {
         let _17 = PrimeField::new(17);
@@ -2298,6 +2934,30 @@ mod test_polynomials {
         assert_eq!(poly, reinterp);
     }
 
+    #[test]
+    fn fast_interpolate_matches_slow_lagrange_interpolation_test() {
+        let field = PrimeFieldBig::new(65537.into());
+        let root_order: i128 = 8;
+        let primitive_root = field.get_primitive_root_of_unity(root_order).0.unwrap();
+
+        let domain: Vec<PrimeFieldElementBig> = (0..root_order)
+            .map(|i| primitive_root.mod_pow(i.into()))
+            .collect();
+        let values: Vec<PrimeFieldElementBig> = generate_random_numbers(root_order as usize, 65537)
+            .iter()
+            .map(|x| pfb(*x, &field))
+            .collect();
+        let points: Vec<(PrimeFieldElementBig, PrimeFieldElementBig)> = domain
+            .iter()
+            .cloned()
+            .zip(values.iter().cloned())
+            .collect();
+
+        let fast = Polynomial::fast_interpolate(&domain, &values, &primitive_root, root_order as usize);
+        let slow = Polynomial::slow_lagrange_interpolation(&points);
+        assert_eq!(slow, fast);
+    }
+
     #[test]
     fn fast_coset_evaluate_test() {
         let _17 = PrimeField::new(17);
@@ -2323,6 +2983,35 @@ mod test_polynomials {
         assert_eq!(reinterp, poly);
     }
 
+    #[test]
+    fn evaluate_domain_ntt_test() {
+        let _193 = PrimeField::new(193);
+        let (root, _) = _193.get_primitive_root_of_unity(8);
+        let root = root.unwrap();
+
+        // 3x^5 + x^2 + 2
+        let _0_193 = PrimeFieldElement::new(0, &_193);
+        let _1_193 = PrimeFieldElement::new(1, &_193);
+        let _2_193 = PrimeFieldElement::new(2, &_193);
+        let _3_193 = PrimeFieldElement::new(3, &_193);
+        let poly = Polynomial {
+            coefficients: vec![_2_193, _0_193, _1_193, _0_193, _0_193, _3_193],
+        };
+
+        let values = poly.evaluate_domain_ntt(&root, 8);
+
+        let mut x = _1_193;
+        let expected: Vec<PrimeFieldElement> = (0..8)
+            .map(|_| {
+                let y = poly.evaluate(&x);
+                x = x * root;
+                y
+            })
+            .collect();
+
+        assert_eq!(expected, values);
+    }
+
     #[test]
     fn fast_coset_divide_test() {
         let _65537 = PrimeFieldBig::new(65537.into());
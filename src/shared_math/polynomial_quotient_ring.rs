@@ -22,6 +22,18 @@ impl PolynomialQuotientRing {
     pub fn get_polynomial_modulus(&self) -> Vec<i128> {
         self.polynomial_modulus.clone()
     }
+
+    /// Like `new`, but for an arbitrary polynomial modulus instead of the fixed
+    /// `x^n+1` shape -- needed for extension fields built from an irreducible
+    /// polynomial that isn't of that form.
+    pub fn new_with_modulus(q: i128, polynomial_modulus: Vec<i128>) -> Self {
+        let n = (polynomial_modulus.len() - 1) as i128;
+        PolynomialQuotientRing {
+            n,
+            q,
+            polynomial_modulus,
+        }
+    }
 }
 
 #[cfg(test)]
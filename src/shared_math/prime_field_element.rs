@@ -1,6 +1,7 @@
 use crate::shared_math::traits::{IdentityValues, ModPowU64, New};
 use crate::utils::{FIRST_TEN_THOUSAND_PRIMES, FIRST_THOUSAND_PRIMES};
 use serde::Serialize;
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops::Add;
 use std::ops::Div;
@@ -16,6 +17,21 @@ pub struct PrimeField {
     pub q: i128,
 }
 
+/// Return value of `PrimeField::find_primitive_root`: the field found and its primitive
+/// root, stored as a raw value so the pair isn't self-referential. Call `root()` to get
+/// the root as a `PrimeFieldElement` of `field`.
+#[derive(Debug, Clone)]
+pub struct PrimitiveRootOfUnity {
+    pub field: PrimeField,
+    root_value: i128,
+}
+
+impl PrimitiveRootOfUnity {
+    pub fn root(&self) -> PrimeFieldElement {
+        PrimeFieldElement::new(self.root_value, &self.field)
+    }
+}
+
 impl PrimeField {
     pub fn new(q: i128) -> Self {
         Self { q }
@@ -35,6 +51,14 @@ impl PrimeField {
         }
     }
 
+    /// Shorthand for `PrimeFieldElement::new(value, self)`, for call sites that
+    /// already have a `&PrimeField` in hand and want to build elements of it without
+    /// repeating the field argument -- e.g. turning a raw `i128` codeword into field
+    /// elements in a test.
+    pub fn element(&self, value: i128) -> PrimeFieldElement {
+        PrimeFieldElement::new(value, self)
+    }
+
     // Verify that field prime is of the form a*k + b
     // where a, b, and k are all integers
     pub fn prime_check(&self, a: i128, b: i128) -> bool {
@@ -56,6 +80,42 @@ impl PrimeField {
         ret
     }
 
+    /// Like `get_power_series`, but validates that `root` actually has order
+    /// `expected_order` before returning anything, rather than silently producing a
+    /// too-short (and therefore wrong) domain if it doesn't. Returns `None` if
+    /// `root^expected_order != 1`, or if a smaller power of `root` already equals 1.
+    /// Meant for setting up a FRI domain, where either mistake would otherwise
+    /// surface much later as a cryptic proof failure instead of here, at setup.
+    pub fn get_power_series_checked(&self, root: i128, expected_order: usize) -> Option<Vec<i128>> {
+        let mut val = root;
+        let mut ret: Vec<i128> = vec![1];
+        for _ in 1..expected_order {
+            if val == 1 {
+                return None;
+            }
+            ret.push(val);
+            val = val * root % self.q;
+        }
+
+        if val != 1 {
+            return None;
+        }
+
+        Some(ret)
+    }
+
+    // Like `get_power_series`, but shifted by `offset`: [offset, offset*root, offset*root^2, ...]
+    // of length `len`. This is the input domain for FRI over a coset.
+    pub fn get_coset(&self, offset: i128, root: i128, len: usize) -> Vec<i128> {
+        let mut val = offset % self.q;
+        let mut ret: Vec<i128> = Vec::with_capacity(len);
+        for _ in 0..len {
+            ret.push(val);
+            val = val * root % self.q;
+        }
+        ret
+    }
+
     pub fn get_field_with_primitive_root_of_unity(
         n: i128,
         min_value: i128,
@@ -71,6 +131,18 @@ impl PrimeField {
         *ret = None;
     }
 
+    /// Preferred, out-parameter-free sibling of `get_field_with_primitive_root_of_unity`.
+    /// Finds the smallest prime greater than `min_value` whose field has a primitive `n`th
+    /// root of unity. Since `PrimeFieldElement` borrows the field it belongs to, it can't
+    /// be handed back in the same tuple as a freshly-constructed, owned `PrimeField` (the
+    /// element would outlive the field it borrows); call `.root()` on the result once the
+    /// returned field is bound to a place that will outlive the element.
+    pub fn find_primitive_root(n: i128, min_value: i128) -> Option<PrimitiveRootOfUnity> {
+        let mut ret = None;
+        Self::get_field_with_primitive_root_of_unity(n, min_value, &mut ret);
+        ret.map(|(field, root_value)| PrimitiveRootOfUnity { field, root_value })
+    }
+
     pub fn evaluate_straight_line(
         &self,
         (a, b): (PrimeFieldElement, PrimeFieldElement),
@@ -257,6 +329,42 @@ impl<'a> New for PrimeFieldElement<'_> {
     }
 }
 
+impl<'a> crate::shared_math::traits::FieldElement for PrimeFieldElement<'a> {
+    type Exponent = i128;
+
+    fn zero(&self) -> Self {
+        self.ring_zero()
+    }
+
+    fn one(&self) -> Self {
+        self.ring_one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    fn neg(&self) -> Self {
+        -*self
+    }
+
+    fn inverse(&self) -> Self {
+        self.inv()
+    }
+
+    fn mod_pow(&self, exponent: Self::Exponent) -> Self {
+        PrimeFieldElement::mod_pow(self, exponent)
+    }
+
+    fn from_bytes_raw(&self, buf: &[u8]) -> Self {
+        PrimeFieldElement::from_bytes(self.field, buf)
+    }
+}
+
 impl fmt::Display for PrimeFieldElement<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Pretty printing does not print the modulus value, although I guess it could...
@@ -264,6 +372,27 @@ impl fmt::Display for PrimeFieldElement<'_> {
     }
 }
 
+/// `From<i128>` can't carry a `&PrimeField` along with it, so the field is threaded
+/// through as part of the source tuple instead -- `PrimeFieldElement::from((value,
+/// field))` is equivalent to `PrimeFieldElement::new(value, field)`.
+impl<'a> From<(i128, &'a PrimeField)> for PrimeFieldElement<'a> {
+    fn from((value, field): (i128, &'a PrimeField)) -> Self {
+        PrimeFieldElement::new(value, field)
+    }
+}
+
+impl<'a> TryFrom<PrimeFieldElement<'a>> for i128 {
+    type Error = std::convert::Infallible;
+
+    /// Always succeeds: unlike `PrimeFieldElementBig`'s `BigInt` value, `value` here
+    /// is already a plain `i128` in `[0, field.q)`, so there's nothing that could
+    /// fail to fit. `TryFrom` rather than a plain `From` so both field element types
+    /// convert back to their underlying integer representation the same way.
+    fn try_from(element: PrimeFieldElement<'a>) -> Result<Self, Self::Error> {
+        Ok(element.value)
+    }
+}
+
 impl<'a> PrimeFieldElement<'a> {
     pub fn from_bytes(field: &'a PrimeField, buf: &[u8]) -> PrimeFieldElement<'a> {
         let value = PrimeFieldElement::from_bytes_raw(&field.q, buf);
@@ -321,6 +450,15 @@ impl<'a> PrimeFieldElement<'a> {
         }
     }
 
+    /// Constant-time counterpart of `==`. Useful wherever a field comparison's
+    /// result (and not just its timing) is meant to be secret-independent, such as
+    /// a signature verifier, since `==`'s short-circuiting makes its running time
+    /// correlate with how many leading limbs/bits of the two values agree.
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        use subtle::ConstantTimeEq;
+        self.value.ct_eq(&other.value)
+    }
+
     pub fn legendre_symbol(&self) -> i128 {
         let elem = self.mod_pow((self.field.q - 1) / 2).value;
 
@@ -415,6 +553,74 @@ impl<'a> PrimeFieldElement<'a> {
             field: self.field,
         }
     }
+
+    /// Invert every element of `elements` in a single amortized pass using Montgomery's
+    /// batch-inversion trick (one modular inversion plus `3n` multiplications instead of
+    /// `n` inversions). Panics if any element is zero, or if `elements` is empty.
+    pub fn batch_inverse(elements: &[PrimeFieldElement<'a>]) -> Vec<PrimeFieldElement<'a>> {
+        let field = elements
+            .first()
+            .expect("Cannot batch-invert an empty slice")
+            .field;
+        field.batch_inversion_elements(elements.to_vec())
+    }
+
+    /// Compute a modular square root with the Tonelli-Shanks algorithm. Returns both
+    /// roots `(r, -r)` if `self` is a quadratic residue, `None` otherwise.
+    pub fn sqrt(&self) -> Option<(PrimeFieldElement<'a>, PrimeFieldElement<'a>)> {
+        if self.is_zero() {
+            return Some((*self, *self));
+        }
+
+        if self.legendre_symbol() != 1 {
+            return None;
+        }
+
+        let q = self.field.q;
+
+        // Fast path: q = 3 mod 4 means r = self^((q+1)/4) is a square root.
+        if q % 4 == 3 {
+            let r = self.mod_pow((q + 1) / 4);
+            return Some((r, -r));
+        }
+
+        // General case: factor q - 1 = s * 2^e with s odd.
+        let mut s = q - 1;
+        let mut e = 0u32;
+        while s % 2 == 0 {
+            s /= 2;
+            e += 1;
+        }
+
+        // Find a quadratic non-residue to seed the algorithm.
+        let mut non_residue = PrimeFieldElement::new(2, self.field);
+        while non_residue.legendre_symbol() != -1 {
+            non_residue = non_residue + PrimeFieldElement::new(1, self.field);
+        }
+
+        let mut m = e;
+        let mut c = non_residue.mod_pow(s);
+        let mut t = self.mod_pow(s);
+        let mut r = self.mod_pow((s + 1) / 2);
+
+        while !t.is_one() {
+            // Find the smallest i such that t^(2^i) == 1.
+            let mut i = 0u32;
+            let mut t_pow = t;
+            while !t_pow.is_one() {
+                t_pow = t_pow * t_pow;
+                i += 1;
+            }
+
+            let b = c.mod_pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+
+        Some((r, -r))
+    }
 }
 
 impl<'a> Add for PrimeFieldElement<'a> {
@@ -482,12 +688,52 @@ impl<'a> Neg for PrimeFieldElement<'a> {
 
     fn neg(self) -> Self {
         Self {
-            value: self.field.q - self.value,
+            value: if self.value == 0 { 0 } else { self.field.q - self.value },
             field: self.field,
         }
     }
 }
 
+impl<'a> Add<&PrimeFieldElement<'a>> for &PrimeFieldElement<'a> {
+    type Output = PrimeFieldElement<'a>;
+
+    fn add(self, other: &PrimeFieldElement<'a>) -> PrimeFieldElement<'a> {
+        *self + *other
+    }
+}
+
+impl<'a> Sub<&PrimeFieldElement<'a>> for &PrimeFieldElement<'a> {
+    type Output = PrimeFieldElement<'a>;
+
+    fn sub(self, other: &PrimeFieldElement<'a>) -> PrimeFieldElement<'a> {
+        *self - *other
+    }
+}
+
+impl<'a> Mul<&PrimeFieldElement<'a>> for &PrimeFieldElement<'a> {
+    type Output = PrimeFieldElement<'a>;
+
+    fn mul(self, other: &PrimeFieldElement<'a>) -> PrimeFieldElement<'a> {
+        *self * *other
+    }
+}
+
+impl<'a> Div<&PrimeFieldElement<'a>> for &PrimeFieldElement<'a> {
+    type Output = PrimeFieldElement<'a>;
+
+    fn div(self, other: &PrimeFieldElement<'a>) -> PrimeFieldElement<'a> {
+        *self / *other
+    }
+}
+
+impl<'a> Neg for &PrimeFieldElement<'a> {
+    type Output = PrimeFieldElement<'a>;
+
+    fn neg(self) -> PrimeFieldElement<'a> {
+        -*self
+    }
+}
+
 // p = k*n+1 = 2^32 − 2^20 + 1 = 4293918721
 // p-1=2^20*3^2*5*7*13.
 
@@ -495,8 +741,39 @@ impl<'a> Neg for PrimeFieldElement<'a> {
 mod test_modular_arithmetic {
     #![allow(clippy::just_underscores_and_digits)]
     use super::*;
+    use crate::shared_math::prime_field_element_big::{PrimeFieldBig, PrimeFieldElementBig};
+    use crate::shared_math::traits::FieldElement;
     use crate::utils::generate_random_numbers;
 
+    // Exercises the shared `FieldElement` interface without relying on any particular
+    // implementor, i.e. without trait objects.
+    fn assert_self_times_inverse_is_one<T: FieldElement + std::fmt::Debug + PartialEq>(element: T) {
+        assert_eq!(
+            FieldElement::one(&element),
+            FieldElement::mul(&element, &FieldElement::inverse(&element))
+        );
+    }
+
+    #[test]
+    fn field_element_trait_test() {
+        let field = PrimeField::new(101);
+        let element = PrimeFieldElement::new(17, &field);
+        assert_self_times_inverse_is_one(element);
+
+        let field_big = PrimeFieldBig::new(101.into());
+        let element_big = PrimeFieldElementBig::new(17.into(), &field_big);
+        assert_self_times_inverse_is_one(element_big);
+    }
+
+    #[test]
+    fn find_primitive_root_test() {
+        // Mirrors generate_proof_16_alt_i128's expectation: (n = 16, min_value = 113)
+        // should return (field = mod 193; root = 64).
+        let found = PrimeField::find_primitive_root(16, 113).unwrap();
+        assert_eq!(193i128, found.field.q);
+        assert_eq!(64i128, found.root().value);
+    }
+
     #[test]
     fn batch_inversion_test_small_no_zeros() {
         let input: Vec<i128> = vec![1, 2, 3, 4];
@@ -539,6 +816,45 @@ mod test_modular_arithmetic {
         assert_eq!(vec![1, 3, 2, 4], output_values);
     }
 
+    #[test]
+    fn colinearity_check_with_reference_operators_test() {
+        // A line through (1, 2) and (3, 4): L(x) = x + 1, rewritten from
+        // `Polynomial::are_colinear`'s clone-heavy style using `&a * &b + &c`.
+        let field = PrimeField::new(101);
+        let p0 = (
+            PrimeFieldElement::new(1, &field),
+            PrimeFieldElement::new(2, &field),
+        );
+        let p1 = (
+            PrimeFieldElement::new(3, &field),
+            PrimeFieldElement::new(4, &field),
+        );
+        let p2 = (
+            PrimeFieldElement::new(5, &field),
+            PrimeFieldElement::new(6, &field),
+        );
+
+        let x_diff = &p0.0 - &p1.0;
+        let a = &(&p0.1 - &p1.1) / &x_diff;
+        let b_coefficient = &p0.1 - &(&a * &p0.0);
+        let expected = &(&a * &p2.0) + &b_coefficient;
+        assert_eq!(p2.1, expected);
+        assert_eq!(p1.0 - p0.0, -&x_diff);
+    }
+
+    #[test]
+    fn batch_inverse_test() {
+        let field = PrimeField::new(101);
+        let input = vec![1, 2, 3, 4, 100]
+            .into_iter()
+            .map(|x| PrimeFieldElement::new(x, &field))
+            .collect::<Vec<PrimeFieldElement>>();
+        let output = PrimeFieldElement::batch_inverse(&input);
+        for (a, a_inv) in input.iter().zip(output.iter()) {
+            assert_eq!(PrimeFieldElement::new(1, &field), *a * *a_inv);
+        }
+    }
+
     #[test]
     fn sieve_of_eratosthenes() {
         // Find primes below 100
@@ -556,6 +872,48 @@ mod test_modular_arithmetic {
         println!("sieve successful");
     }
 
+    #[test]
+    fn legendre_symbol_residue_count_test() {
+        let field = PrimeField::new(101);
+        let residue_count = (0..field.q)
+            .filter(|&value| PrimeFieldElement::new(value, &field).legendre_symbol() == 1)
+            .count();
+        assert_eq!((field.q - 1) / 2, residue_count as i128);
+    }
+
+    fn assert_sqrt_matches_legendre_symbol(elem: PrimeFieldElement) {
+        let is_residue = elem.legendre_symbol() != -1;
+        match elem.sqrt() {
+            Some((r, minus_r)) => {
+                assert!(is_residue, "{} claimed a root but is a non-residue", elem.value);
+                assert_eq!(elem, r * r, "sqrt root squares back to {}", elem.value);
+                assert_eq!(elem, minus_r * minus_r);
+                assert_eq!(r, -minus_r);
+            }
+            None => {
+                assert!(!is_residue, "{} is a residue but sqrt returned None", elem.value);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_test() {
+        // 193 = 1 mod 4 exercises the general Tonelli-Shanks path; 101 is 1 mod 4 too,
+        // while 65537 = 1 mod 4 is a larger prime used elsewhere for FRI tests.
+        for &q in &[193i128, 101, 65537] {
+            let field = PrimeField::new(q);
+            for value in 0..std::cmp::min(q, 1000) {
+                assert_sqrt_matches_legendre_symbol(PrimeFieldElement::new(value, &field));
+            }
+        }
+
+        // q = 3 mod 4 exercises the fast path.
+        let field = PrimeField::new(7);
+        for value in 0..field.q {
+            assert_sqrt_matches_legendre_symbol(PrimeFieldElement::new(value, &field));
+        }
+    }
+
     #[test]
     fn get_power_series_test() {
         let field = PrimeField::new(113);
@@ -563,6 +921,35 @@ mod test_modular_arithmetic {
         println!("{:?}", power_series);
     }
 
+    #[test]
+    fn get_power_series_checked_accepts_correct_order_test() {
+        let field = PrimeField::new(113);
+        // 40 has order 16 mod 113.
+        assert_eq!(
+            Some(field.get_power_series(40)),
+            field.get_power_series_checked(40, 16)
+        );
+    }
+
+    #[test]
+    fn get_power_series_checked_rejects_wrong_order_test() {
+        let field = PrimeField::new(113);
+        // 40 has order 16 mod 113, so 40^2 only has order 8 -- passing it off as a
+        // root of order 16 should be rejected rather than silently truncated.
+        let wrong_order_root = PrimeFieldElement::new(40, &field).mod_pow(2).value;
+        assert_eq!(None, field.get_power_series_checked(wrong_order_root, 16));
+    }
+
+    #[test]
+    fn get_coset_test() {
+        let field = PrimeField::new(113);
+        let power_series = field.get_power_series(40);
+        let offset = 7;
+        let coset = field.get_coset(offset, 40, power_series.len());
+        let expected: Vec<i128> = power_series.iter().map(|x| x * offset % field.q).collect();
+        assert_eq!(expected, coset);
+    }
+
     // get_generator_domain
     #[test]
     fn get_generator_domain_test() {
@@ -809,4 +1196,36 @@ mod test_modular_arithmetic {
             PrimeFieldElement::new(1, &_1931)
         );
     }
+
+    #[test]
+    fn ct_eq_agrees_with_eq_test() {
+        let field = PrimeField::new(7919);
+        let lhs = generate_random_numbers(50, field.q);
+        let rhs = generate_random_numbers(50, field.q);
+        for (a, b) in lhs.into_iter().zip(rhs.into_iter()) {
+            let a_elem = PrimeFieldElement::new(a, &field);
+            let b_elem = PrimeFieldElement::new(b, &field);
+            assert_eq!(a_elem == b_elem, a_elem.ct_eq(&b_elem).into());
+            assert_eq!(true, a_elem.ct_eq(&a_elem).into());
+        }
+    }
+
+    #[test]
+    fn from_and_try_from_i128_round_trip_test() {
+        let field = PrimeField::new(7919);
+        let raw_values = vec![0, 1, 42, 7918, 7919, 12345];
+
+        let elements: Vec<PrimeFieldElement> = raw_values
+            .iter()
+            .map(|&value| PrimeFieldElement::from((value, &field)))
+            .collect();
+        assert_eq!(elements, raw_values.iter().map(|&v| field.element(v)).collect::<Vec<_>>());
+
+        let recovered: Vec<i128> = elements
+            .into_iter()
+            .map(|element| i128::try_from(element).unwrap())
+            .collect();
+        let expected: Vec<i128> = raw_values.iter().map(|&v| v % field.q).collect();
+        assert_eq!(expected, recovered);
+    }
 }
@@ -3,7 +3,7 @@ use crate::utils::{FIRST_TEN_THOUSAND_PRIMES, FIRST_THOUSAND_PRIMES};
 use num_bigint::BigInt;
 use num_traits::One;
 use num_traits::Zero;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::convert::Into;
 use std::hash::Hash;
 use std::ops::Add;
@@ -80,6 +80,18 @@ impl PrimeFieldBig {
         ret
     }
 
+    // Like `get_power_series`, but shifted by `offset`: [offset, offset*root, offset*root^2, ...]
+    // of length `len`. This is the input domain for FRI over a coset.
+    pub fn get_coset(&self, offset: BigInt, root: BigInt, len: usize) -> Vec<BigInt> {
+        let mut val: BigInt = offset % self.q.clone();
+        let mut ret: Vec<BigInt> = Vec::with_capacity(len);
+        for _ in 0..len {
+            ret.push(val.clone());
+            val = val.clone() * root.clone() % self.q.clone();
+        }
+        ret
+    }
+
     pub fn get_field_with_primitive_root_of_unity(
         n: i128,
         min_value: i128,
@@ -267,6 +279,36 @@ pub struct PrimeFieldElementBig<'a> {
     pub field: &'a PrimeFieldBig,
 }
 
+/// Owned counterpart of `PrimeFieldElementBig` that carries the modulus by value instead
+/// of borrowing a `PrimeFieldBig`. `PrimeFieldElementBig` can't derive `Deserialize`
+/// because of its field reference; this type exists so field elements can round-trip
+/// through serde anyway, at the cost of repeating the modulus per element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PrimeFieldElementBigOwned {
+    pub value: BigInt,
+    pub modulus: BigInt,
+}
+
+impl<'a> From<&PrimeFieldElementBig<'a>> for PrimeFieldElementBigOwned {
+    fn from(element: &PrimeFieldElementBig<'a>) -> Self {
+        Self {
+            value: element.value.clone(),
+            modulus: element.field.q.clone(),
+        }
+    }
+}
+
+impl PrimeFieldElementBigOwned {
+    /// Reattach the owned value to a borrowed field, recovering a `PrimeFieldElementBig`.
+    /// The caller is responsible for passing a field whose modulus matches `self.modulus`.
+    pub fn to_element<'a>(&self, field: &'a PrimeFieldBig) -> PrimeFieldElementBig<'a> {
+        PrimeFieldElementBig {
+            value: self.value.clone(),
+            field,
+        }
+    }
+}
+
 impl<'a> ModPowU64 for PrimeFieldElementBig<'a> {
     fn mod_pow_u64(&self, pow: u64) -> Self {
         self.mod_pow(pow.into())
@@ -313,6 +355,42 @@ impl<'a> New for PrimeFieldElementBig<'_> {
     }
 }
 
+impl<'a> crate::shared_math::traits::FieldElement for PrimeFieldElementBig<'a> {
+    type Exponent = BigInt;
+
+    fn zero(&self) -> Self {
+        self.ring_zero()
+    }
+
+    fn one(&self) -> Self {
+        self.ring_one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.clone() * other.clone()
+    }
+
+    fn neg(&self) -> Self {
+        -self.clone()
+    }
+
+    fn inverse(&self) -> Self {
+        self.inv()
+    }
+
+    fn mod_pow(&self, exponent: Self::Exponent) -> Self {
+        PrimeFieldElementBig::mod_pow(self, exponent)
+    }
+
+    fn from_bytes_raw(&self, buf: &[u8]) -> Self {
+        PrimeFieldElementBig::from_bytes(self.field, buf)
+    }
+}
+
 impl fmt::Display for PrimeFieldElementBig<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Pretty printing does not print the modulus value, although I guess it could...
@@ -376,6 +454,17 @@ impl<'a> PrimeFieldElementBig<'a> {
         }
     }
 
+    /// `Big` counterpart of `PrimeFieldElement::batch_inverse`: invert every element of
+    /// `elements` with Montgomery's batch-inversion trick. Panics if any element is
+    /// zero, or if `elements` is empty.
+    pub fn batch_inverse(elements: &[PrimeFieldElementBig<'a>]) -> Vec<PrimeFieldElementBig<'a>> {
+        let field = elements
+            .first()
+            .expect("Cannot batch-invert an empty slice")
+            .field;
+        field.batch_inversion_elements(elements.to_vec())
+    }
+
     pub fn legendre_symbol(&self) -> BigInt {
         let one = BigInt::one();
         let elem = self
@@ -453,6 +542,12 @@ impl<'a> PrimeFieldElementBig<'a> {
             return 1.into();
         }
 
+        // A negative exponent means inverting the base first and exponentiating by
+        // the absolute value, i.e. a^(-e) = (a^-1)^e.
+        if pow < BigInt::zero() {
+            return self.inv().mod_pow_raw(-pow);
+        }
+
         let mut acc: BigInt = BigInt::one();
         let mod_value: BigInt = self.field.q.clone();
         let res = self.value.clone();
@@ -562,6 +657,38 @@ impl<'a> Add for &PrimeFieldElementBig<'a> {
     }
 }
 
+impl<'a> Sub for &PrimeFieldElementBig<'a> {
+    type Output = PrimeFieldElementBig<'a>;
+
+    fn sub(self, other: Self) -> PrimeFieldElementBig<'a> {
+        self.clone() - other.clone()
+    }
+}
+
+impl<'a> Mul for &PrimeFieldElementBig<'a> {
+    type Output = PrimeFieldElementBig<'a>;
+
+    fn mul(self, other: Self) -> PrimeFieldElementBig<'a> {
+        self.clone() * other.clone()
+    }
+}
+
+impl<'a> Div for &PrimeFieldElementBig<'a> {
+    type Output = PrimeFieldElementBig<'a>;
+
+    fn div(self, other: Self) -> PrimeFieldElementBig<'a> {
+        self.clone() / other.clone()
+    }
+}
+
+impl<'a> Neg for &PrimeFieldElementBig<'a> {
+    type Output = PrimeFieldElementBig<'a>;
+
+    fn neg(self) -> PrimeFieldElementBig<'a> {
+        -self.clone()
+    }
+}
+
 #[cfg(test)]
 mod test_modular_arithmetic_big {
     #![allow(clippy::just_underscores_and_digits)]
@@ -578,6 +705,21 @@ mod test_modular_arithmetic_big {
             .collect::<Vec<BigInt>>()
     }
 
+    #[test]
+    fn legendre_symbol_residue_count_test() {
+        let field = PrimeFieldBig::new(b(101));
+        let mut value = b(0);
+        let mut residue_count = 0;
+        while value < field.q {
+            if PrimeFieldElementBig::new(value.clone(), &field).legendre_symbol() == BigInt::one()
+            {
+                residue_count += 1;
+            }
+            value += 1;
+        }
+        assert_eq!((field.q.clone() - 1) / 2, b(residue_count));
+    }
+
     #[test]
     fn batch_inversion_test_small_no_zeros() {
         let input: Vec<BigInt> = vec![b(1), b(2), b(3), b(4)];
@@ -667,6 +809,65 @@ mod test_modular_arithmetic_big {
         assert_eq!(bs(vec![1, 3, 2, 4]), output_values);
     }
 
+    #[test]
+    fn mod_pow_negative_and_large_exponent_test() {
+        let field = PrimeFieldBig::new(b(101));
+        let elem = PrimeFieldElementBig::new(b(3), &field);
+
+        assert_eq!(PrimeFieldElementBig::new(b(1), &field), elem.mod_pow(b(0)));
+        assert_eq!(
+            PrimeFieldElementBig::new(b(3).modpow(&b(200), &b(101)), &field),
+            elem.mod_pow(b(200))
+        );
+        assert_eq!(elem.inv(), elem.mod_pow(-b(1)));
+    }
+
+    #[test]
+    fn colinearity_check_with_reference_operators_test() {
+        // A line through (1, 2) and (3, 4): L(x) = x + 1. Rewritten from
+        // `Polynomial::are_colinear`'s `a.clone() * point.0.clone() + b.clone()` style,
+        // using `&a * &b + &c` instead of threading `.clone()` through every operand.
+        let field = PrimeFieldBig::new(b(101));
+        let p0 = (
+            PrimeFieldElementBig::new(b(1), &field),
+            PrimeFieldElementBig::new(b(2), &field),
+        );
+        let p1 = (
+            PrimeFieldElementBig::new(b(3), &field),
+            PrimeFieldElementBig::new(b(4), &field),
+        );
+        let p2 = (
+            PrimeFieldElementBig::new(b(5), &field),
+            PrimeFieldElementBig::new(b(6), &field),
+        );
+
+        let x_diff = &p0.0 - &p1.0;
+        let a = &(&p0.1 - &p1.1) / &x_diff;
+        let b_coefficient = &p0.1 - &(&a * &p0.0);
+        let expected = &(&a * &p2.0) + &b_coefficient;
+        assert_eq!(p2.1, expected);
+
+        // Negating `x_diff` with `Neg` and checking it against the by-value
+        // `p1.0 - p0.0` exercises the remaining reference operators.
+        assert_eq!(p1.0.clone() - p0.0.clone(), -&x_diff);
+    }
+
+    #[test]
+    fn batch_inverse_test() {
+        let field = PrimeFieldBig::new(b(101));
+        let input = bs(vec![1, 2, 3, 4, 100])
+            .into_iter()
+            .map(|x| PrimeFieldElementBig::new(x, &field))
+            .collect::<Vec<PrimeFieldElementBig>>();
+        let output = PrimeFieldElementBig::batch_inverse(&input);
+        for (a, a_inv) in input.iter().zip(output.iter()) {
+            assert_eq!(
+                PrimeFieldElementBig::new(b(1), &field),
+                a.clone() * a_inv.clone()
+            );
+        }
+    }
+
     #[test]
     fn sieve_of_eratosthenes() {
         // Find primes below 100
@@ -694,6 +895,33 @@ mod test_modular_arithmetic_big {
         assert_eq!(b(1), power_series.first().unwrap().to_owned());
     }
 
+    #[test]
+    fn prime_field_element_big_owned_serde_round_trip_test() {
+        let field = PrimeFieldBig::new(b(193));
+        let element = PrimeFieldElementBig::new(b(17), &field);
+        let owned = PrimeFieldElementBigOwned::from(&element);
+
+        let serialized = bincode::serialize(&owned).unwrap();
+        let deserialized: PrimeFieldElementBigOwned = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(owned, deserialized);
+
+        let reattached = deserialized.to_element(&field);
+        assert_eq!(element, reattached);
+    }
+
+    #[test]
+    fn get_coset_test() {
+        let field = PrimeFieldBig::new(b(113));
+        let power_series = field.get_power_series(b(40));
+        let offset = b(7);
+        let coset = field.get_coset(offset.clone(), b(40), power_series.len());
+        let expected: Vec<BigInt> = power_series
+            .iter()
+            .map(|x| x.clone() * offset.clone() % field.q.clone())
+            .collect();
+        assert_eq!(expected, coset);
+    }
+
     // get_generator_domain
     #[test]
     fn get_generator_domain_test() {
@@ -373,6 +373,50 @@ impl<'a> PrimeFieldPolynomial<'a> {
         self.div(&polynomial_modulus).1
     }
 
+    /// Inverse of `self` in `R[x]/(f(x))`, where `f` is this ring's polynomial
+    /// modulus, via the extended Euclidean algorithm for polynomials: running the
+    /// algorithm on `(f, self mod f)` produces `old_t` such that
+    /// `old_r == old_t * self + (some multiple of f)`, where `old_r` ends up being
+    /// `gcd(f, self)`. `self` has an inverse mod `f` exactly when that gcd is a
+    /// nonzero constant, in which case `old_t` rescaled by the gcd's inverse is it;
+    /// returns `None` otherwise (`self` and `f` share a nontrivial common factor).
+    pub fn inverse(&self) -> Option<Self> {
+        if self.coefficients.is_empty() {
+            return None;
+        }
+
+        let f = Self {
+            coefficients: self.pqr.get_polynomial_modulus(),
+            pqr: self.pqr,
+        };
+
+        let (mut old_r, mut r) = (f, self.modulus());
+        let (mut old_t, mut t) = (
+            Self::additive_identity(self.pqr),
+            Self::polynomium_from_int(1, self.pqr),
+        );
+
+        while !r.coefficients.is_empty() {
+            let (quotient, remainder) = old_r.div(&r);
+            old_r = r;
+            r = remainder;
+
+            let new_t = old_t.sub(&quotient.mul(&t));
+            old_t = t;
+            t = new_t;
+        }
+
+        if old_r.coefficients.is_empty() || old_r.degree() != 0 {
+            return None;
+        }
+
+        let gcd_constant = old_r.get_constant_term();
+        let (_, gcd_inv, _) = PrimeFieldElement::eea(gcd_constant, self.pqr.q);
+        let gcd_inv = (gcd_inv % self.pqr.q + self.pqr.q) % self.pqr.q;
+
+        Some(old_t.scalar_mul(gcd_inv).modulus())
+    }
+
     pub fn mul(&self, other: &PrimeFieldPolynomial<'a>) -> Self {
         // If either polynomial is zero, return zero
         if self.coefficients.is_empty() || other.coefficients.is_empty() {
@@ -942,4 +986,36 @@ mod test_polynomials {
         assert!(scalar_mul.coefficients.len() == scalar_mul_float.coefficients.len());
         assert_eq!(expected_scalar_mul_float, scalar_mul_float);
     }
+
+    #[test]
+    fn inverse_test() {
+        // GF(7)[x]/(x^2+1): x^2+1 has no root mod 7, so this quotient ring is the
+        // field GF(49). Invert a handful of nonzero elements and check the product
+        // with their inverse reduces to the multiplicative identity.
+        let pqr = PolynomialQuotientRing::new(2, 7); // degree: 2, mod prime: 7
+        let one = PrimeFieldPolynomial {
+            coefficients: vec![1],
+            pqr: &pqr,
+        };
+        for coefficients in [vec![2], vec![1, 1], vec![3, 4], vec![0, 2]] {
+            let element = PrimeFieldPolynomial {
+                coefficients,
+                pqr: &pqr,
+            };
+            let inverse = element.inverse().unwrap();
+            assert_eq!(one, element.mul(&inverse).modulus());
+        }
+    }
+
+    #[test]
+    fn inverse_of_non_invertible_element_is_none_test() {
+        // Mod 5, x^2+1 factors as (x-2)(x-3), so (x-2) shares a factor with the
+        // ring's modulus and has no inverse in GF(5)[x]/(x^2+1).
+        let pqr = PolynomialQuotientRing::new(2, 5); // degree: 2, mod prime: 5
+        let x_minus_two = PrimeFieldPolynomial {
+            coefficients: vec![3, 1],
+            pqr: &pqr,
+        };
+        assert!(x_minus_two.inverse().is_none());
+    }
 }
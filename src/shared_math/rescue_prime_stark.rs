@@ -251,12 +251,19 @@ impl<'a> RescuePrime<'a> {
         state
     }
 
+    /// Run the full Rescue-Prime permutation on a state of `m` field elements. Exposed so
+    /// callers outside this module (e.g. a standalone sponge) can drive the permutation
+    /// without going through `hash`'s fixed single-element absorb/squeeze.
+    pub fn permute(&self, state: Vec<PrimeFieldElementBig<'a>>) -> Vec<PrimeFieldElementBig<'a>> {
+        (0..self.steps_count).fold(state, |state, i| self.hash_round(state, i))
+    }
+
     /// Return the Rescue-Prime hash value
     pub fn hash(&self, input: &PrimeFieldElementBig<'a>) -> PrimeFieldElementBig<'a> {
         let mut state = vec![input.ring_zero(); self.m];
         state[0] = input.to_owned();
 
-        state = (0..self.steps_count).fold(state, |state, i| self.hash_round(state, i));
+        state = self.permute(state);
 
         state[0].clone()
     }
@@ -402,12 +409,51 @@ impl<'a> RescuePrime<'a> {
     }
 }
 
+/// Rescue-Prime as a standalone hash, independent of the STARK that proves knowledge of a
+/// preimage. Reuses the round constants and MDS matrix from `RescuePrime::from_tutorial`,
+/// so it only supports that tutorial's `407 * 2^119 + 1` prime field; there is no i128-sized field in
+/// this codebase whose modulus those constants were generated for.
+///
+/// The permutation has a single rate lane (capacity 1), so inputs are absorbed one at a
+/// time with a permutation call in between, and the digest is the single resulting rate
+/// element.
+pub fn rescue_prime_hash<'a>(
+    field: &'a PrimeFieldBig,
+    input: &[PrimeFieldElementBig<'a>],
+) -> Vec<PrimeFieldElementBig<'a>> {
+    let rescue_prime = RescuePrime::from_tutorial(field);
+    let zero = PrimeFieldElementBig::new(0.into(), field);
+    let mut state = vec![zero.clone(); rescue_prime.m];
+    for element in input {
+        state[0] = state[0].clone() + element.clone();
+        state = rescue_prime.permute(state);
+    }
+
+    vec![state[0].clone()]
+}
+
 #[cfg(test)]
 mod rescue_prime_start_test {
-    use crate::{shared_math::stark::Stark, util_types::proof_stream::ProofStream};
+    use crate::{
+        shared_math::stark::{Stark, StarkParameters},
+        util_types::proof_stream::ProofStream,
+    };
 
     use super::*;
 
+    #[test]
+    fn rescue_prime_hash_matches_instance_method_test() {
+        let field = PrimeFieldBig::new((407u128 * (1 << 119) + 1).into());
+        let one = PrimeFieldElementBig::new(1.into(), &field);
+        let expected_output_one =
+            PrimeFieldElementBig::new(244180265933090377212304188905974087294u128.into(), &field);
+
+        assert_eq!(
+            vec![expected_output_one],
+            rescue_prime_hash(&field, &[one])
+        );
+    }
+
     #[test]
     fn hash_test_vectors() {
         // Values found on:
@@ -504,17 +550,17 @@ mod rescue_prime_start_test {
     #[test]
     fn rp_stark_test() {
         let field = PrimeFieldBig::new((407u128 * (1 << 119) + 1).into());
-        let expansion_factor = 4usize;
-        let colinearity_checks_count = 2usize;
-        let transition_constraints_degree = 2usize;
+        // RescuePrime's round function raises register values to `alpha` (3 for the
+        // tutorial parameters), so the transition constraints are degree-3 polynomials
+        // in the trace registers.
+        let transition_constraints_degree = 3usize;
         let generator =
             PrimeFieldElementBig::new(85408008396924667383611388730472331217u128.into(), &field);
         let rescue_prime_stark = RescuePrime::from_tutorial(&field);
 
         let mut stark = Stark::new(
             &field,
-            expansion_factor,
-            colinearity_checks_count,
+            StarkParameters::default(),
             rescue_prime_stark.m,
             rescue_prime_stark.steps_count + 1,
             transition_constraints_degree,
@@ -1,26 +1,90 @@
-use super::prime_field_element_big::{PrimeFieldBig, PrimeFieldElementBig};
+use super::prime_field_element_big::{
+    PrimeFieldBig, PrimeFieldElementBig, PrimeFieldElementBigOwned,
+};
 use super::stark::{Stark, DOCUMENT_HASH_LENGTH};
 use crate::shared_math::rescue_prime_stark::RescuePrime;
 use crate::util_types::proof_stream::ProofStream;
 use crate::utils::blake3_digest;
 use rand::RngCore;
+use serde::{de::DeserializeOwned, Serialize};
 use std::error::Error;
+use subtle::ConstantTimeEq;
 
 #[derive(Clone, Debug)]
 pub struct SecretKey<'a> {
     pub value: PrimeFieldElementBig<'a>,
 }
 
+impl<'a> SecretKey<'a> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_length_prepended(&PrimeFieldElementBigOwned::from(&self.value))
+    }
+
+    pub fn from_bytes(field: &'a PrimeFieldBig, bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let owned: PrimeFieldElementBigOwned = deserialize_length_prepended(bytes)?;
+        Ok(SecretKey {
+            value: owned.to_element(field),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PublicKey<'a> {
     pub value: PrimeFieldElementBig<'a>,
 }
 
+impl<'a> PublicKey<'a> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_length_prepended(&PrimeFieldElementBigOwned::from(&self.value))
+    }
+
+    pub fn from_bytes(field: &'a PrimeFieldBig, bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let owned: PrimeFieldElementBigOwned = deserialize_length_prepended(bytes)?;
+        Ok(PublicKey {
+            value: owned.to_element(field),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Signature {
     pub proof: Vec<u8>,
 }
 
+impl Signature {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_length_prepended(&self.proof)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let proof: Vec<u8> = deserialize_length_prepended(bytes)?;
+        Ok(Signature { proof })
+    }
+}
+
+// Length-prepended encoding shared by the `to_bytes`/`from_bytes` pairs above: a 4-byte
+// little-endian length followed by the bincode-serialized payload. Mirrors the framing
+// `ProofStream::enqueue_length_prepended`/`dequeue_length_prepended` use for transcripts.
+fn serialize_length_prepended<T: Serialize>(item: &T) -> Vec<u8> {
+    let serialized = bincode::serialize(item).unwrap();
+    let length: u32 = serialized.len() as u32;
+    let mut bytes = bincode::serialize(&length).unwrap();
+    bytes.extend(serialized);
+    bytes
+}
+
+fn deserialize_length_prepended<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+    if bytes.len() < 4 {
+        return Err(Box::from("serialization is truncated: missing length prefix"));
+    }
+    let length: u32 = bincode::deserialize(&bytes[0..4])?;
+    let length = length as usize;
+    if bytes.len() < 4 + length {
+        return Err(Box::from("serialization is truncated: payload shorter than length prefix"));
+    }
+    Ok(bincode::deserialize(&bytes[4..4 + length])?)
+}
+
 pub struct RPSSS<'a> {
     pub field: PrimeFieldBig,
     pub rp: RescuePrime<'a>,
@@ -42,9 +106,15 @@ impl<'a> RPSSS<'a> {
     }
 
     pub fn verify(&self, public_key: &PublicKey, signature: &Signature, document: &[u8]) -> bool {
-        // Verify that the signature is prepended with the hash of the document
+        // Verify that the signature is prepended with the hash of the document. Compared
+        // in constant time so a forger can't use the comparison's timing to learn how many
+        // leading bytes of a guessed hash already matched.
         let document_hash = blake3_digest(document);
-        if signature.proof[0..DOCUMENT_HASH_LENGTH] != document_hash {
+        if signature.proof[0..DOCUMENT_HASH_LENGTH]
+            .ct_eq(&document_hash[..])
+            .unwrap_u8()
+            == 0
+        {
             return false;
         }
 
@@ -62,6 +132,53 @@ impl<'a> RPSSS<'a> {
         res.is_ok()
     }
 
+    // Verify many (document, signature, public_key) triples against this RPSSS instance's
+    // field and STARK setup. The AIR transition constraints are derived once and reused
+    // for every triple, where repeated `verify` calls would rebuild them on each call.
+    pub fn verify_batch(
+        &self,
+        documents: &[Vec<u8>],
+        signatures: &[Signature],
+        public_keys: &[PublicKey],
+    ) -> Result<Vec<bool>, Box<dyn Error>> {
+        if documents.len() != signatures.len() || documents.len() != public_keys.len() {
+            return Err(Box::from(
+                "documents, signatures, and public_keys must have the same length",
+            ));
+        }
+
+        let transition_constraints = self.rp.get_air_constraints(&self.stark.omicron);
+        let results = documents
+            .iter()
+            .zip(signatures.iter())
+            .zip(public_keys.iter())
+            .map(|((document, signature), public_key)| {
+                let document_hash = blake3_digest(document);
+                if signature.proof[0..DOCUMENT_HASH_LENGTH]
+                    .ct_eq(&document_hash[..])
+                    .unwrap_u8()
+                    == 0
+                {
+                    return false;
+                }
+
+                let mut proof_stream: ProofStream = signature.proof.clone().into();
+                proof_stream.set_index(DOCUMENT_HASH_LENGTH);
+
+                let boundary_constraints = self.rp.get_boundary_constraints(&public_key.value);
+                let res = self.stark.verify(
+                    &mut proof_stream,
+                    transition_constraints.clone(),
+                    boundary_constraints,
+                );
+
+                res.is_ok()
+            })
+            .collect();
+
+        Ok(results)
+    }
+
     pub fn sign(&self, sk: &SecretKey, document: &[u8]) -> Result<Signature, Box<dyn Error>> {
         let (output, trace) = self.rp.eval_and_trace(&sk.value);
         let document_hash = blake3_digest(document);
@@ -149,4 +266,111 @@ mod test_rpsss {
         assert!(!rpsss.verify(&pk, &signature, &bad_document));
         assert!(rpsss.verify(&pk, &signature, &document));
     }
+
+    #[test]
+    fn verify_batch_rejects_mismatched_lengths_test() {
+        let modulus: BigInt = (407u128 * (1 << 119) + 1).into();
+        let field = PrimeFieldBig::new(modulus);
+        let (mut stark, rp): (Stark, RescuePrime) = test_stark::get_tutorial_stark(&field);
+        stark.prover_preprocess();
+        let rpsss = RPSSS {
+            field: field.clone(),
+            stark,
+            rp,
+        };
+
+        let (sk, pk) = rpsss.keygen();
+        let document = "Hello Neptune!".to_string().into_bytes();
+        let signature = rpsss.sign(&sk, &document).unwrap();
+
+        let res = rpsss.verify_batch(&[document], &[signature.clone(), signature], &[pk]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn verify_batch_flags_only_the_corrupted_signature_test() {
+        let modulus: BigInt = (407u128 * (1 << 119) + 1).into();
+        let field = PrimeFieldBig::new(modulus);
+        let (mut stark, rp): (Stark, RescuePrime) = test_stark::get_tutorial_stark(&field);
+        stark.prover_preprocess();
+        let rpsss = RPSSS {
+            field: field.clone(),
+            stark,
+            rp,
+        };
+
+        let documents: Vec<Vec<u8>> = vec![
+            "Hello Neptune!".to_string().into_bytes(),
+            "Hello Uranus!".to_string().into_bytes(),
+            "Hello Pluto!".to_string().into_bytes(),
+        ];
+
+        let mut public_keys = vec![];
+        let mut signatures = vec![];
+        for document in documents.iter() {
+            let (sk, pk) = rpsss.keygen();
+            let signature = rpsss.sign(&sk, document).unwrap();
+            public_keys.push(pk);
+            signatures.push(signature);
+        }
+
+        // Corrupt only the second signature.
+        if let Some(last) = signatures[1].proof.last_mut() {
+            *last ^= 0x01;
+        }
+
+        let results = rpsss
+            .verify_batch(&documents, &signatures, &public_keys)
+            .unwrap();
+        assert_eq!(vec![true, false, true], results);
+    }
+
+    #[test]
+    fn key_and_signature_serialization_round_trip_test() {
+        let modulus: BigInt = (407u128 * (1 << 119) + 1).into();
+        let field = PrimeFieldBig::new(modulus);
+        let (mut stark, rp): (Stark, RescuePrime) = test_stark::get_tutorial_stark(&field);
+        stark.prover_preprocess();
+        let rpsss = RPSSS {
+            field: field.clone(),
+            stark,
+            rp,
+        };
+
+        let (sk, pk) = rpsss.keygen();
+        let document = "Hello Neptune!".to_string().into_bytes();
+        let signature = rpsss.sign(&sk, &document).unwrap();
+
+        let sk_from_bytes = SecretKey::from_bytes(&field, &sk.to_bytes()).unwrap();
+        assert_eq!(sk.value, sk_from_bytes.value);
+
+        let pk_from_bytes = PublicKey::from_bytes(&field, &pk.to_bytes()).unwrap();
+        assert_eq!(pk.value, pk_from_bytes.value);
+
+        let signature_from_bytes = Signature::from_bytes(&signature.to_bytes()).unwrap();
+        assert_eq!(signature.proof, signature_from_bytes.proof);
+        assert!(rpsss.verify(&pk_from_bytes, &signature_from_bytes, &document));
+    }
+
+    #[test]
+    fn key_and_signature_deserialization_rejects_truncated_input_test() {
+        let modulus: BigInt = (407u128 * (1 << 119) + 1).into();
+        let field = PrimeFieldBig::new(modulus);
+        let (mut stark, rp): (Stark, RescuePrime) = test_stark::get_tutorial_stark(&field);
+        stark.prover_preprocess();
+        let rpsss = RPSSS {
+            field: field.clone(),
+            stark,
+            rp,
+        };
+
+        let (sk, pk) = rpsss.keygen();
+        let document = "Hello Neptune!".to_string().into_bytes();
+        let signature = rpsss.sign(&sk, &document).unwrap();
+
+        assert!(SecretKey::from_bytes(&field, &[]).is_err());
+        assert!(SecretKey::from_bytes(&field, &sk.to_bytes()[0..2]).is_err());
+        assert!(PublicKey::from_bytes(&field, &pk.to_bytes()[0..2]).is_err());
+        assert!(Signature::from_bytes(&signature.to_bytes()[0..2]).is_err());
+    }
 }
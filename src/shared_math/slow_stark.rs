@@ -1,3 +1,13 @@
+//! The reference STARK implementation: the same protocol as `stark`, minus
+//! preprocessing/caching (every `prove`/`verify` call recomputes the transition
+//! zerofier from scratch) and minus opening that zerofier's Merkle tree in the proof --
+//! this module's verifier simply trusts the zerofier it computes locally. That makes
+//! this the simpler of the two formats: it's a subset of `stark`'s, so
+//! `Stark::verify` here can check a proof produced by `stark::Stark::prove` (see
+//! `test_slow_stark::canonical_stark_proof_verifies_under_slow_stark_verifier_test`),
+//! guarding against the two implementations drifting apart on everything both of them
+//! check. New code should build on `stark` instead of this module.
+
 use num_bigint::BigInt;
 use rand::{RngCore, SeedableRng};
 
@@ -802,4 +812,133 @@ pub mod test_slow_stark {
             Err(err) => panic!("Verification of STARK proof failed with error: {}", err),
         };
     }
+
+    // Single-register transition constraint for MiMC: next = previous^3 + round_constant(x),
+    // where round_constant(x) is the unique low-degree polynomial through the round constants
+    // indexed by cycle. Mirrors `stark::test_stark::mimc_air_constraints`.
+    fn mimc_air_constraints<'a>(
+        omicron: &'a PrimeFieldElementBig,
+        round_constants: &[PrimeFieldElementBig<'a>],
+    ) -> Vec<MPolynomial<PrimeFieldElementBig<'a>>> {
+        let domain = omicron.get_generator_domain();
+        let points: Vec<(PrimeFieldElementBig, PrimeFieldElementBig)> = domain
+            .iter()
+            .zip(round_constants.iter())
+            .map(|(x, y)| (x.to_owned(), y.to_owned()))
+            .collect();
+        let round_constant_coefficients =
+            Polynomial::slow_lagrange_interpolation(&points).coefficients;
+        let round_constant_polynomial = MPolynomial::lift(
+            Polynomial {
+                coefficients: round_constant_coefficients,
+            },
+            0,
+        );
+
+        let variables = MPolynomial::variables(3, omicron.ring_one());
+        let previous_state = variables[1].clone();
+        let next_state = variables[2].clone();
+        let air = next_state
+            - (previous_state.mod_pow(3.into(), omicron.ring_one()) + round_constant_polynomial);
+
+        vec![air]
+    }
+
+    fn mimc_boundary_constraints<'a>(
+        input: &PrimeFieldElementBig<'a>,
+        output: &PrimeFieldElementBig<'a>,
+        no_steps: usize,
+    ) -> Vec<BoundaryConstraint<'a>> {
+        vec![
+            BoundaryConstraint {
+                cycle: 0,
+                register: 0,
+                value: input.to_owned(),
+            },
+            BoundaryConstraint {
+                cycle: no_steps,
+                register: 0,
+                value: output.to_owned(),
+            },
+        ]
+    }
+
+    // `stark::Stark`'s proofs are a strict superset of `slow_stark::Stark`'s: the
+    // canonical prover additionally opens the transition zerofier at the FRI
+    // colinearity-check positions (see the module docs), which `slow_stark::Stark`
+    // trusts out-of-band instead. `ProofStream::dequeue` reads sequentially without
+    // requiring the whole stream to be consumed, so `slow_stark::Stark::verify` can
+    // check a `stark::Stark` proof directly, simply never reading those extra bytes --
+    // that's the "conversion" between the two formats, and it only works in this
+    // direction. This test proves a MiMC statement with the canonical prover and
+    // checks it verifies under the reference verifier, guarding against the two
+    // implementations drifting apart on everything both of them check.
+    #[test]
+    fn canonical_stark_proof_verifies_under_slow_stark_verifier_test() {
+        use crate::shared_math::mimc_stark::mimc_forward;
+        use crate::shared_math::stark::{Stark as CanonicalStark, StarkParameters};
+
+        let no_steps = 3usize;
+        let register_count = 1;
+        let cycles_count = no_steps + 1;
+        let transition_constraints_degree = 3;
+
+        let prime = 5 * 2i128.pow(25) + 1;
+        let field = PrimeFieldBig::new(prime.into());
+        let generator = field.get_primitive_root_of_unity(prime - 1).0.unwrap();
+        let round_constants: Vec<PrimeFieldElementBig> = vec![7, 256, 117]
+            .into_iter()
+            .map(|x| PrimeFieldElementBig::new(x.into(), &field))
+            .collect();
+
+        let expansion_factor = 4;
+        let colinearity_checks_count = 2;
+        let mut canonical_stark = CanonicalStark::new(
+            &field,
+            StarkParameters {
+                expansion_factor,
+                num_colinearity_checks: colinearity_checks_count,
+            },
+            register_count,
+            cycles_count,
+            transition_constraints_degree,
+            generator.clone(),
+        );
+        canonical_stark.prover_preprocess();
+
+        let input = PrimeFieldElementBig::new(3.into(), &field);
+        let computational_trace = mimc_forward(&input, no_steps, &round_constants);
+        let output = computational_trace[no_steps].clone();
+        let trace: Vec<Vec<PrimeFieldElementBig>> = computational_trace
+            .into_iter()
+            .map(|value| vec![value])
+            .collect();
+        let transition_constraints =
+            mimc_air_constraints(&canonical_stark.omicron, &round_constants);
+        let boundary_constraints = mimc_boundary_constraints(&input, &output, no_steps);
+
+        let mut proof_stream = ProofStream::default();
+        canonical_stark
+            .prove(
+                trace,
+                transition_constraints.clone(),
+                boundary_constraints.clone(),
+                &mut proof_stream,
+            )
+            .expect("Failed to produce STARK proof for MiMC trace with the canonical stark");
+
+        let slow_stark = Stark::new(
+            &field,
+            expansion_factor,
+            colinearity_checks_count,
+            register_count,
+            cycles_count,
+            transition_constraints_degree,
+            generator,
+        );
+
+        slow_stark
+            .verify(&mut proof_stream, transition_constraints, boundary_constraints)
+            .expect("canonical stark's proof should verify under the reference slow_stark verifier");
+    }
 }
@@ -1,3 +1,15 @@
+//! The canonical STARK implementation. Same protocol as `slow_stark`, with two
+//! differences: the transition zerofier is preprocessed and cached on the `Stark`
+//! instance instead of recomputed on every `prove`/`verify` call, and `prove` also
+//! opens the transition zerofier's Merkle tree at the FRI colinearity-check positions
+//! so the verifier doesn't have to trust it out-of-band. That second difference makes
+//! this module's proofs a strict superset of `slow_stark`'s -- `slow_stark::Stark`'s
+//! simpler verifier can check a proof from here directly (see
+//! `slow_stark::test_slow_stark::canonical_stark_proof_verifies_under_slow_stark_verifier_test`),
+//! but not the other way around. New code should build on this module; `slow_stark` is
+//! kept around as the simpler, easier-to-audit reference implementation this one is
+//! checked against.
+
 use num_bigint::BigInt;
 use rand::{RngCore, SeedableRng};
 
@@ -43,6 +55,26 @@ pub struct StarkPreprocessedValues<'a> {
     prover: Option<StarkPreprocessedValuesProver<'a>>,
 }
 
+/// FRI security knobs for a `Stark` instance: how many colinearity checks the verifier
+/// runs and how much the FRI domain is blown up relative to the trace. Bundled into one
+/// struct so the trade-off between proof size and soundness error is set in one place,
+/// instead of as loose positional arguments to `Stark::new`. `Default` reproduces the
+/// parameters the STARK modules used before this struct existed.
+#[derive(Clone, Debug)]
+pub struct StarkParameters {
+    pub expansion_factor: usize,
+    pub num_colinearity_checks: usize,
+}
+
+impl Default for StarkParameters {
+    fn default() -> Self {
+        Self {
+            expansion_factor: 4,
+            num_colinearity_checks: 2,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Stark<'a> {
     expansion_factor: usize,
@@ -63,13 +95,16 @@ pub struct Stark<'a> {
 impl<'a> Stark<'a> {
     pub fn new(
         field: &'a PrimeFieldBig,
-        expansion_factor: usize,
-        colinearity_check_count: usize,
+        parameters: StarkParameters,
         register_count: usize,
         cycle_count: usize,
         transition_constraints_degree: usize,
         generator: PrimeFieldElementBig<'a>,
     ) -> Self {
+        let StarkParameters {
+            expansion_factor,
+            num_colinearity_checks: colinearity_check_count,
+        } = parameters;
         let num_randomizers = 4 * colinearity_check_count;
         let original_trace_length = cycle_count;
         let randomized_trace_length = original_trace_length + num_randomizers;
@@ -819,25 +854,133 @@ impl<'a> Stark<'a> {
 pub mod test_stark {
     use num_bigint::BigInt;
 
+    use crate::shared_math::mimc_stark::mimc_forward;
     use crate::shared_math::rescue_prime_stark::RescuePrime;
 
     use super::*;
 
+    // Single-register transition constraint for MiMC: next = previous^3 + round_constant(x),
+    // where round_constant(x) is the unique low-degree polynomial through the round constants
+    // indexed by cycle.
+    fn mimc_air_constraints<'a>(
+        omicron: &'a PrimeFieldElementBig,
+        round_constants: &[PrimeFieldElementBig<'a>],
+    ) -> Vec<MPolynomial<PrimeFieldElementBig<'a>>> {
+        let domain = omicron.get_generator_domain();
+        let points: Vec<(PrimeFieldElementBig, PrimeFieldElementBig)> = domain
+            .iter()
+            .zip(round_constants.iter())
+            .map(|(x, y)| (x.to_owned(), y.to_owned()))
+            .collect();
+        let round_constant_coefficients =
+            Polynomial::slow_lagrange_interpolation(&points).coefficients;
+        let round_constant_polynomial = MPolynomial::lift(
+            Polynomial {
+                coefficients: round_constant_coefficients,
+            },
+            0,
+        );
+
+        let variables = MPolynomial::variables(3, omicron.ring_one());
+        let previous_state = variables[1].clone();
+        let next_state = variables[2].clone();
+        let air = next_state
+            - (previous_state.mod_pow(3.into(), omicron.ring_one()) + round_constant_polynomial);
+
+        vec![air]
+    }
+
+    fn mimc_boundary_constraints<'a>(
+        input: &PrimeFieldElementBig<'a>,
+        output: &PrimeFieldElementBig<'a>,
+        no_steps: usize,
+    ) -> Vec<BoundaryConstraint<'a>> {
+        vec![
+            BoundaryConstraint {
+                cycle: 0,
+                register: 0,
+                value: input.to_owned(),
+            },
+            BoundaryConstraint {
+                cycle: no_steps,
+                register: 0,
+                value: output.to_owned(),
+            },
+        ]
+    }
+
+    #[test]
+    fn mimc_stark_via_generic_api_test() {
+        let no_steps = 3usize;
+        let register_count = 1;
+        let cycles_count = no_steps + 1;
+        let transition_constraints_degree = 3;
+
+        let prime = 5 * 2i128.pow(25) + 1;
+        let field = PrimeFieldBig::new(prime.into());
+        let generator = field.get_primitive_root_of_unity(prime - 1).0.unwrap();
+        let round_constants: Vec<PrimeFieldElementBig> = vec![7, 256, 117]
+            .into_iter()
+            .map(|x| PrimeFieldElementBig::new(x.into(), &field))
+            .collect();
+
+        let mut stark = Stark::new(
+            &field,
+            StarkParameters::default(),
+            register_count,
+            cycles_count,
+            transition_constraints_degree,
+            generator,
+        );
+        stark.prover_preprocess();
+
+        let input = PrimeFieldElementBig::new(3.into(), &field);
+        let computational_trace = mimc_forward(&input, no_steps, &round_constants);
+        let output = computational_trace[no_steps].clone();
+        let trace: Vec<Vec<PrimeFieldElementBig>> = computational_trace
+            .into_iter()
+            .map(|value| vec![value])
+            .collect();
+        let transition_constraints = mimc_air_constraints(&stark.omicron, &round_constants);
+        let boundary_constraints = mimc_boundary_constraints(&input, &output, no_steps);
+
+        let mut proof_stream = ProofStream::default();
+        stark
+            .prove(
+                trace,
+                transition_constraints.clone(),
+                boundary_constraints.clone(),
+                &mut proof_stream,
+            )
+            .expect("Failed to produce STARK proof for MiMC trace");
+
+        stark
+            .verify(&mut proof_stream, transition_constraints, boundary_constraints)
+            .expect("Verification of MiMC STARK proof failed");
+    }
+
     pub fn get_tutorial_stark<'a>(field: &'a PrimeFieldBig) -> (Stark<'a>, RescuePrime<'a>) {
-        let expansion_factor = 4;
-        let colinearity_checks_count = 2;
+        get_tutorial_stark_with_parameters(field, StarkParameters::default())
+    }
+
+    pub fn get_tutorial_stark_with_parameters<'a>(
+        field: &'a PrimeFieldBig,
+        parameters: StarkParameters,
+    ) -> (Stark<'a>, RescuePrime<'a>) {
         let rescue_prime = RescuePrime::from_tutorial(&field);
         let register_count = rescue_prime.m;
         let cycles_count = rescue_prime.steps_count + 1;
-        let transition_constraints_degree = 2;
+        // RescuePrime's round function raises register values to `alpha` (3 for the
+        // tutorial parameters), so the transition constraints are degree-3 polynomials
+        // in the trace registers.
+        let transition_constraints_degree = 3;
         let generator =
             PrimeFieldElementBig::new(85408008396924667383611388730472331217u128.into(), &field);
 
         (
             Stark::new(
                 &field,
-                expansion_factor,
-                colinearity_checks_count,
+                parameters,
                 register_count,
                 cycles_count,
                 transition_constraints_degree,
@@ -956,4 +1099,35 @@ pub mod test_stark {
             Err(err) => panic!("Verification of STARK proof failed with error: {}", err),
         };
     }
+
+    #[test]
+    fn rescue_prime_stark_with_higher_colinearity_checks_test() {
+        let modulus: BigInt = (407u128 * (1 << 119) + 1).into();
+        let field = PrimeFieldBig::new(modulus);
+        let parameters = StarkParameters {
+            num_colinearity_checks: 4,
+            ..StarkParameters::default()
+        };
+        let (mut stark, rescue_prime) = get_tutorial_stark_with_parameters(&field, parameters);
+        stark.prover_preprocess();
+
+        let input = PrimeFieldElementBig::new(228894434762048332457318u128.into(), &field);
+        let trace = rescue_prime.trace(&input);
+        let output_element = trace[rescue_prime.steps_count][0].clone();
+        let transition_constraints = rescue_prime.get_air_constraints(&stark.omicron);
+        let boundary_constraints = rescue_prime.get_boundary_constraints(&output_element);
+        let mut proof_stream = ProofStream::default();
+
+        stark
+            .prove(
+                trace,
+                transition_constraints.clone(),
+                boundary_constraints.clone(),
+                &mut proof_stream,
+            )
+            .expect("Failed to produce STARK proof with a higher colinearity check count");
+        stark
+            .verify(&mut proof_stream, transition_constraints, boundary_constraints)
+            .expect("Verification of STARK proof with a higher colinearity check count failed");
+    }
 }
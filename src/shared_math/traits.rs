@@ -1,7 +1,3 @@
-use std::convert::{From, TryInto};
-use std::fmt::{Debug, Display};
-use std::hash::Hash;
-
 pub trait IdentityValues {
     fn is_zero(&self) -> bool;
     fn is_one(&self) -> bool;
@@ -26,18 +22,22 @@ pub trait New {
     fn new_from_usize(&self, value: usize) -> Self;
 }
 
-pub trait FieldElement:
-    num_traits::Num
-    + Clone
-    + Hash
-    + Debug
-    + Display
-    + PartialEq
-    + Eq
-    + PartialOrd
-    + Ord
-    + From<i128>
-    + TryInto<i128>
-{
-    fn is_power_of_2(&self) -> bool;
+// Common interface shared by the field element types used across the crate
+// (PrimeFieldElement, PrimeFieldElementBig, and friends), which otherwise differ in
+// their underlying integer representation and in whether construction needs a runtime
+// field reference. Each method takes `&self` purely as a witness of which field to
+// operate in, following the same convention as `New::new_from_usize`. This is the
+// prerequisite for writing a generic FRI prover and a generic NTT that work over any of
+// these representations.
+pub trait FieldElement: Sized + Clone {
+    type Exponent;
+
+    fn zero(&self) -> Self;
+    fn one(&self) -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+    fn neg(&self) -> Self;
+    fn inverse(&self) -> Self;
+    fn mod_pow(&self, exponent: Self::Exponent) -> Self;
+    fn from_bytes_raw(&self, buf: &[u8]) -> Self;
 }
@@ -1,15 +1,16 @@
+use super::traits::IdentityValues;
 use std::convert::TryFrom;
 use std::fmt::Display;
-use std::ops::Add;
+use std::ops::{Add, Mul};
 
 #[derive(Debug, Clone)]
-pub struct Matrix<T: num_traits::Num + Clone> {
+pub struct Matrix<T: Clone> {
     length: usize,
     height: usize,
     values: Vec<T>,
 }
 
-impl<T: num_traits::Num + Clone> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+impl<T: Clone> TryFrom<Vec<Vec<T>>> for Matrix<T> {
     type Error = &'static str;
     // Rewrite using match and pattern matching
     fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
@@ -72,12 +73,61 @@ where
     }
 }
 
+// Bounded by `Add`/`Mul`/`IdentityValues` rather than `num_traits::Num`, for the same
+// reason as `Vector::dot`/`scalar_mul`: this needs to work for field elements such as
+// `PrimeFieldElement`, which can't implement `Num`.
+impl<U> Matrix<U>
+where
+    U: Add<Output = U> + Mul<Output = U> + Clone + Copy + IdentityValues,
+{
+    pub fn mul_vector(&self, v: &[U]) -> Vec<U> {
+        assert_eq!(
+            self.length,
+            v.len(),
+            "matrix length must match vector length. Got matrix length {} and vector length {}",
+            self.length,
+            v.len()
+        );
+        (0..self.height)
+            .map(|i| {
+                (0..self.length).fold(v[0].ring_zero(), |acc, j| {
+                    acc + self.values[i * self.length + j] * v[j]
+                })
+            })
+            .collect()
+    }
+
+    pub fn mul_matrix(&self, other: &Matrix<U>) -> Matrix<U> {
+        assert_eq!(
+            self.length, other.height,
+            "left matrix length must match right matrix height. Got left length {} and right height {}",
+            self.length, other.height
+        );
+        let zero = self.values[0].ring_zero();
+        let mut values = Vec::with_capacity(self.height * other.length);
+        for i in 0..self.height {
+            for k in 0..other.length {
+                let acc = (0..self.length).fold(zero, |acc, j| {
+                    acc + self.values[i * self.length + j] * other.values[j * other.length + k]
+                });
+                values.push(acc);
+            }
+        }
+
+        Matrix {
+            length: other.length,
+            height: self.height,
+            values,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub struct Vector<T: num_traits::Num + Clone + Copy> {
+pub struct Vector<T: Clone + Copy> {
     values: Vec<T>,
 }
 
-impl<T: num_traits::Num + Clone + Copy + Display> From<Vec<T>> for Vector<T> {
+impl<T: Clone + Copy> From<Vec<T>> for Vector<T> {
     fn from(values: Vec<T>) -> Self {
         Vector { values }
     }
@@ -223,6 +273,35 @@ where
     }
 }
 
+// Bounded by `Add`/`Mul`/`IdentityValues` rather than `num_traits::Num`, since field
+// elements such as `PrimeFieldElement` can't implement `Num` (it requires a no-argument
+// `zero()`/`one()`, but a field element can't produce one without borrowing a field).
+impl<U> Vector<U>
+where
+    U: Add<Output = U> + Mul<Output = U> + Clone + Copy + IdentityValues,
+{
+    pub fn dot(&self, other: &Self) -> U {
+        assert_eq!(
+            self.values.len(),
+            other.values.len(),
+            "vectors must have the same length to compute a dot product. Got: {} and {}",
+            self.values.len(),
+            other.values.len()
+        );
+        let zero = self.values[0].ring_zero();
+        self.values
+            .iter()
+            .zip(other.values.iter())
+            .fold(zero, |acc, (&a, &b)| acc + a * b)
+    }
+
+    pub fn scalar_mul(&self, scalar: U) -> Self {
+        Self {
+            values: self.values.iter().map(|&v| v * scalar).collect(),
+        }
+    }
+}
+
 impl<U: num_traits::Num + Clone + Copy> Add for Vector<U> {
     type Output = Self;
 
@@ -283,4 +362,96 @@ mod test_vectors {
         // Verify that all row lengths must be equal when creating matrices
         assert!(Matrix::try_from(vec![vec![1, 2], vec![3, 4, 2], vec![5, 6]]).is_err());
     }
+
+    #[test]
+    fn dot_product_test() {
+        use super::*;
+        use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
+
+        let field = PrimeField::new(101);
+        let to_vector = |values: Vec<i128>| -> Vector<PrimeFieldElement> {
+            Vector::from(
+                values
+                    .into_iter()
+                    .map(|x| PrimeFieldElement::new(x, &field))
+                    .collect::<Vec<PrimeFieldElement>>(),
+            )
+        };
+
+        let a = to_vector(vec![1, 2, 3]);
+        let b = to_vector(vec![4, 5, 6]);
+        assert_eq!(32, a.dot(&b).value);
+
+        let scaled = a.scalar_mul(PrimeFieldElement::new(2, &field));
+        assert_eq!(to_vector(vec![2, 4, 6]), scaled);
+    }
+
+    #[test]
+    #[should_panic(expected = "vectors must have the same length")]
+    fn dot_product_length_mismatch_panics_test() {
+        use super::*;
+        use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
+
+        let field = PrimeField::new(101);
+        let a: Vector<PrimeFieldElement> =
+            Vector::from(vec![PrimeFieldElement::new(1, &field)]);
+        let b: Vector<PrimeFieldElement> = Vector::from(vec![
+            PrimeFieldElement::new(1, &field),
+            PrimeFieldElement::new(2, &field),
+        ]);
+        a.dot(&b);
+    }
+
+    #[test]
+    fn mul_vector_test() {
+        use super::*;
+        use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
+
+        let field = PrimeField::new(101);
+        let e = |x: i128| PrimeFieldElement::new(x, &field);
+
+        // | 1 2 |   | 5 |   | 1*5 + 2*6 |   | 17 |
+        // | 3 4 | * | 6 | = | 3*5 + 4*6 | = | 39 |
+        let matrix: Matrix<PrimeFieldElement> =
+            Matrix::try_from(vec![vec![e(1), e(2)], vec![e(3), e(4)]]).unwrap();
+        let vector = vec![e(5), e(6)];
+
+        let result = matrix.mul_vector(&vector);
+        assert_eq!(2, result.len());
+        assert_eq!(17, result[0].value);
+        assert_eq!(39, result[1].value);
+    }
+
+    #[test]
+    fn mul_matrix_test() {
+        use super::*;
+        use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
+
+        let field = PrimeField::new(101);
+        let e = |x: i128| PrimeFieldElement::new(x, &field);
+
+        let identity: Matrix<PrimeFieldElement> =
+            Matrix::try_from(vec![vec![e(1), e(0)], vec![e(0), e(1)]]).unwrap();
+        let matrix: Matrix<PrimeFieldElement> =
+            Matrix::try_from(vec![vec![e(1), e(2)], vec![e(3), e(4)]]).unwrap();
+
+        let product = identity.mul_matrix(&matrix);
+        let expected_values: Vec<i128> = matrix.values.iter().map(|x| x.value).collect();
+        let actual_values: Vec<i128> = product.values.iter().map(|x| x.value).collect();
+        assert_eq!(expected_values, actual_values);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix length must match vector length")]
+    fn mul_vector_dimension_mismatch_panics_test() {
+        use super::*;
+        use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
+
+        let field = PrimeField::new(101);
+        let e = |x: i128| PrimeFieldElement::new(x, &field);
+
+        let matrix: Matrix<PrimeFieldElement> =
+            Matrix::try_from(vec![vec![e(1), e(2)], vec![e(3), e(4)]]).unwrap();
+        matrix.mul_vector(&[e(1)]);
+    }
 }
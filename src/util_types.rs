@@ -1,2 +1,3 @@
+pub mod hash_utils;
 pub mod merkle_tree;
 pub mod proof_stream;
@@ -0,0 +1,47 @@
+// Types that know how to turn themselves into Merkle-tree leaf hash preimages
+// without going through a general-purpose serializer first. `bincode` adds
+// length-prefix/format overhead on top of every leaf, which is wasted work for
+// types that are already a fixed number of bytes. `MerkleTree::from_vec_raw`
+// uses this trait instead of `bincode::serialize` for such leaves.
+pub trait Hashable {
+    fn to_hash_preimage(&self) -> Vec<u8>;
+}
+
+impl Hashable for i128 {
+    fn to_hash_preimage(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+}
+
+impl Hashable for u64 {
+    fn to_hash_preimage(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+}
+
+impl Hashable for [u8; 32] {
+    fn to_hash_preimage(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod hash_utils_tests {
+    use super::*;
+    use crate::utils::decode_hex;
+    use std::convert::TryInto;
+
+    #[test]
+    fn i128_hash_preimage_pins_known_leaf_digest_test() {
+        // blake3(1i128.to_be_bytes()) =
+        // 07ed55c1e924d41ae49a06619ac6e79648a9bfc239be57b55bf79139967a7cb0
+        let expected: [u8; 32] = decode_hex(
+            "07ed55c1e924d41ae49a06619ac6e79648a9bfc239be57b55bf79139967a7cb0",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let digest = *blake3::hash(1i128.to_hash_preimage().as_slice()).as_bytes();
+        assert_eq!(expected, digest);
+    }
+}
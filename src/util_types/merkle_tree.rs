@@ -1,8 +1,26 @@
 use crate::shared_math::other::log_2_floor;
+use crate::util_types::hash_utils::Hashable;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "serialization-serde")]
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "serialization-serde")]
+use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Serialize};
 use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+#[cfg(feature = "serialization-serde")]
+use std::marker::PhantomData;
+
+// Prefix bytes used by `from_vec_domain_separated`/`verify_proof_domain_separated`
+// to keep a leaf hash from ever colliding with an internal-node hash.
+const LEAF_HASH_PREFIX: u8 = 0x00;
+const NODE_HASH_PREFIX: u8 = 0x01;
+
+// `MerkleTree`/`PartialAuthenticationPath` is the only Merkle implementation in this
+// crate; there is no `MerkleTreeVector`/`Node`-based alternative to deprecate, and
+// `get_multi_proof` already takes `&[usize]`.
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Node<T> {
@@ -10,11 +28,70 @@ pub struct Node<T> {
     hash: [u8; 32],
 }
 
+impl<T> Node<T> {
+    pub fn new(value: Option<T>, hash: [u8; 32]) -> Self {
+        Node { value, hash }
+    }
+
+    pub fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MerkleTree<T> {
     root_hash: [u8; 32],
     nodes: Vec<Node<T>>,
     height: u64,
+    // Number of leaves actually committed to so far. Equal to `nodes.len() / 2`
+    // (the tree's capacity) for every tree built by a `from_*` constructor, but
+    // can be smaller than capacity for a tree grown with `push`, which doubles
+    // capacity ahead of need rather than one leaf at a time.
+    num_leaves: usize,
+}
+
+// Only the leaf values are serialized; the internal nodes (and their hashes) are
+// recomputed from the leaves on deserialization, since re-hashing is cheap and
+// this keeps a persisted tree from carrying redundant data.
+#[cfg(feature = "serialization-serde")]
+impl<T: Clone + Debug + Serialize + PartialEq> Serialize for MerkleTree<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("MerkleTree", 1)?;
+        state.serialize_field("leaves", &self.to_vec())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serialization-serde")]
+impl<'de, T: Clone + Debug + Serialize + Deserialize<'de> + PartialEq> Deserialize<'de>
+    for MerkleTree<T>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MerkleTreeVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Clone + Debug + Serialize + Deserialize<'de> + PartialEq> Visitor<'de>
+            for MerkleTreeVisitor<T>
+        {
+            type Value = MerkleTree<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct MerkleTree")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let leaves: Vec<T> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                Ok(MerkleTree::from_vec(&leaves))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "MerkleTree",
+            &["leaves"],
+            MerkleTreeVisitor(PhantomData),
+        )
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -27,19 +104,146 @@ impl<T: Clone + Debug + Serialize + PartialEq> PartialAuthenticationPath<T> {
     /// Given a proof_element: CompressedAuthenticationPath<T>, this returns the value
     /// `proof_element.0[0].clone().unwrap().value.unwrap();`
     pub fn get_value(&self) -> T {
+        self.get_value_ref().clone()
+    }
+
+    /// Like `get_value`, but borrows instead of cloning -- for callers, e.g. verifiers,
+    /// that only need to read the value rather than own it.
+    pub fn get_value_ref(&self) -> &T {
         match self.0.first() {
             None => panic!("CompressedAuthenticationPath was empty"),
             Some(option) => match option {
                 None => panic!("First element of CompressedAuthenticationPath was pruned"),
                 Some(node) => match &node.value {
                     None => panic!("No value of first element of CompressedAuthenticationPath"),
-                    Some(val) => val.clone(),
+                    Some(val) => val,
                 },
             },
         }
     }
 }
 
+/// Shared traversal behind `verify_multi_proof_detailed` and
+/// `verify_multi_proof_detailed_raw`: reassembles the partial tree implied by
+/// `proof` and checks it against `root_hash`, deferring to `verify_leaf` (which
+/// differs between the two callers only in how it recomputes a leaf's hash from
+/// its value) for the final per-opening check.
+fn verify_multi_proof_detailed_with<T: Clone + Debug + Serialize + PartialEq>(
+    root_hash: [u8; 32],
+    indices: &[usize],
+    proof: &[PartialAuthenticationPath<T>],
+    verify_leaf: impl Fn([u8; 32], u64, Vec<Node<T>>) -> bool,
+) -> Result<(), usize> {
+    // compressed proofs can only be verified for all indices,
+    // meaning that all indices for the proof values must be known.
+    // This restriction is put in since the pruned parts of the
+    // multi proof are currently reassembled using the indices
+    // and some parts of the proof would be missing if all the proof
+    // elements were not represented in the indices argument.
+    if indices.len() != proof.len() {
+        return Err(0);
+    }
+    // Vacuously true: there's nothing to open, so there's nothing to disagree with.
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    let mut partial_tree: HashMap<u64, Node<T>> = HashMap::new();
+    let mut proof_clone: Vec<PartialAuthenticationPath<T>> = proof.to_owned();
+    let half_tree_size = 2u64.pow(proof_clone[0].0.len() as u32 - 1);
+
+    // Reject any index that doesn't correspond to a leaf of this tree, and
+    // reject duplicate indices whose proofs disagree on the opened leaf --
+    // a malicious prover could otherwise smuggle two different values in
+    // under the same claimed index.
+    let mut seen_leaves: HashMap<usize, Node<T>> = HashMap::new();
+    for (i, b) in indices.iter().zip(proof_clone.iter()) {
+        if *i >= half_tree_size as usize {
+            return Err(0);
+        }
+        let leaf = match &b.0[0] {
+            Some(node) => node.clone(),
+            None => return Err(0),
+        };
+        if let Some(existing) = seen_leaves.get(i) {
+            if existing != &leaf {
+                return Err(0);
+            }
+        } else {
+            seen_leaves.insert(*i, leaf);
+        }
+    }
+
+    for (i, b) in indices.iter().zip(proof_clone.iter_mut()) {
+        let mut index = half_tree_size + *i as u64;
+        partial_tree.insert(index, b.0[0].clone().unwrap());
+        for elem in b.0.iter_mut().skip(1) {
+            if let Some(i) = elem.clone() {
+                partial_tree.insert(index ^ 1, i);
+            }
+            index /= 2;
+        }
+    }
+
+    let mut complete = false;
+    let mut hasher = blake3::Hasher::new();
+    while !complete {
+        complete = true;
+        //let mut keys: Vec<usize> = partial_tree.iter().copied().map(|x| x / 2).collect();
+        let mut keys: Vec<u64> = partial_tree.keys().copied().map(|x| x / 2).collect();
+        keys.sort_by_key(|w| Reverse(*w));
+        for key in keys {
+            if partial_tree.contains_key(&(key * 2))
+                && partial_tree.contains_key(&(key * 2 + 1))
+                && !partial_tree.contains_key(&key)
+            {
+                hasher.update(&partial_tree[&(key * 2)].hash[..]);
+                hasher.update(&partial_tree[&(key * 2 + 1)].hash[..]);
+                partial_tree.insert(
+                    key,
+                    Node {
+                        value: None,
+                        hash: *hasher.finalize().as_bytes(),
+                    },
+                );
+                hasher.reset();
+                complete = false;
+            }
+        }
+    }
+
+    for (position, (i, b)) in indices.iter().zip(proof_clone.iter_mut()).enumerate() {
+        let mut index = half_tree_size + *i as u64;
+        for elem in b.0.iter_mut().skip(1) {
+            if *elem == None {
+                // If the Merkle tree/proof is manipulated, the value partial_tree[&(index ^ 1)]
+                // is not guaranteed to exist. So have to  check
+                // whether it exists and return false if it does not
+                if !partial_tree.contains_key(&(index ^ 1)) {
+                    return Err(position);
+                }
+
+                *elem = Some(partial_tree[&(index ^ 1)].clone());
+            }
+            partial_tree.insert(index ^ 1, elem.clone().unwrap());
+            index /= 2;
+        }
+    }
+
+    for i in 0..indices.len() {
+        let proof_clone_unwrapped: Vec<Node<T>> = proof_clone[i]
+            .0
+            .clone()
+            .into_iter()
+            .map(|x| x.unwrap())
+            .collect();
+        if !verify_leaf(root_hash, indices[i] as u64, proof_clone_unwrapped) {
+            return Err(i);
+        }
+    }
+    Ok(())
+}
+
 impl<T: Clone + Serialize + Debug + PartialEq> MerkleTree<T> {
     pub fn verify_proof(root_hash: [u8; 32], index: u64, proof: Vec<Node<T>>) -> bool {
         let mut mut_index = index + 2u64.pow(proof.len() as u32);
@@ -69,13 +273,123 @@ impl<T: Clone + Serialize + Debug + PartialEq> MerkleTree<T> {
     }
 
     pub fn to_vec(&self) -> Vec<T> {
-        self.nodes[self.nodes.len() / 2..self.nodes.len()]
+        let capacity = self.nodes.len() / 2;
+        self.nodes[capacity..capacity + self.num_leaves]
             .iter()
             .map(|x| x.value.clone().unwrap())
             .collect()
     }
 
+    /// Commit to a trace where each row is the full state of one step, so that a single
+    /// authentication path opens every column of that step at once. This is just `from_vec`
+    /// specialized to rows (`Vec<T>` leaves), exposed under a name that matches how STARK
+    /// provers think about their trace table.
+    pub fn from_rows(rows: &[Vec<T>]) -> MerkleTree<Vec<T>> {
+        MerkleTree::from_vec(rows)
+    }
+
     pub fn from_vec(values: &[T]) -> Self {
+        // The degenerate empty tree: no leaves, no internal nodes, and a root
+        // that's the hash of the empty byte string rather than of any leaf.
+        if values.is_empty() {
+            return MerkleTree {
+                root_hash: *blake3::hash(&[]).as_bytes(),
+                nodes: vec![],
+                height: 0,
+                num_leaves: 0,
+            };
+        }
+
+        // verify that length of input is power of 2
+        if values.len() & (values.len() - 1) != 0 {
+            panic!("Size of input for Merkle tree must be a power of 2");
+        }
+
+        let mut nodes: Vec<Node<T>> = vec![
+            Node {
+                value: None,
+                hash: [0u8; 32],
+            };
+            2 * values.len()
+        ];
+        for i in 0..values.len() {
+            nodes[values.len() + i].hash =
+                *blake3::hash(bincode::serialize(&values[i]).unwrap().as_slice()).as_bytes();
+            nodes[values.len() + i].value = Some(values[i].clone());
+        }
+
+        // loop from `len(L) - 1` to 1
+        let mut hasher = blake3::Hasher::new();
+        for i in (1..(values.len())).rev() {
+            hasher.update(&nodes[i * 2].hash[..]);
+            hasher.update(&nodes[i * 2 + 1].hash[..]);
+            nodes[i].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+        }
+
+        // nodes[0] is never used for anything.
+        MerkleTree {
+            root_hash: nodes[1].hash,
+            nodes,
+            height: log_2_floor(values.len() as u64) + 1,
+            num_leaves: values.len(),
+        }
+    }
+
+    /// Same as `from_vec`, but consumes an iterator instead of a slice, so the
+    /// caller doesn't need to materialize the leaves into a `Vec<T>` before
+    /// building the tree. `len` must match the number of items `leaves` yields;
+    /// it's needed up front to size the (still fully in-memory) node array.
+    pub fn from_iter<I: Iterator<Item = T>>(leaves: I, len: usize) -> Self {
+        // verify that length of input is power of 2
+        if len & (len - 1) != 0 {
+            panic!("Size of input for Merkle tree must be a power of 2");
+        }
+
+        let mut nodes: Vec<Node<T>> = vec![
+            Node {
+                value: None,
+                hash: [0u8; 32],
+            };
+            2 * len
+        ];
+        let mut hasher = blake3::Hasher::new();
+        let mut count = 0usize;
+        for (i, value) in leaves.enumerate() {
+            hasher.update(bincode::serialize(&value).unwrap().as_slice());
+            nodes[len + i].hash = *hasher.finalize().as_bytes();
+            nodes[len + i].value = Some(value);
+            hasher.reset();
+            count += 1;
+        }
+        assert_eq!(len, count, "iterator must yield exactly `len` leaves");
+
+        // loop from `len(L) - 1` to 1
+        for i in (1..len).rev() {
+            hasher.update(&nodes[i * 2].hash[..]);
+            hasher.update(&nodes[i * 2 + 1].hash[..]);
+            nodes[i].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+        }
+
+        // nodes[0] is never used for anything.
+        MerkleTree {
+            root_hash: nodes[1].hash,
+            nodes,
+            height: log_2_floor(len as u64) + 1,
+            num_leaves: len,
+        }
+    }
+
+    /// Same as `from_vec`, but prefixes every leaf-hash preimage with
+    /// `LEAF_HASH_PREFIX` and every internal-node-hash preimage with
+    /// `NODE_HASH_PREFIX`. Without this domain separation, an attacker could
+    /// present an internal node's hash as if it were a leaf hash (or vice versa)
+    /// in a second-preimage attack. Pair with `verify_proof_domain_separated`.
+    /// Roots (and proofs) built this way differ from those produced by
+    /// `from_vec` for the same leaves, so this is opt-in via a separate
+    /// constructor rather than a change to `from_vec`'s behavior.
+    pub fn from_vec_domain_separated(values: &[T]) -> Self {
         // verify that length of input is power of 2
         if values.len() & (values.len() - 1) != 0 {
             panic!("Size of input for Merkle tree must be a power of 2");
@@ -84,35 +398,641 @@ impl<T: Clone + Serialize + Debug + PartialEq> MerkleTree<T> {
         let mut nodes: Vec<Node<T>> = vec![
             Node {
                 value: None,
-                hash: [0u8; 32],
-            };
-            2 * values.len()
-        ];
+                hash: [0u8; 32],
+            };
+            2 * values.len()
+        ];
+        let mut hasher = blake3::Hasher::new();
+        for i in 0..values.len() {
+            hasher.update(&[LEAF_HASH_PREFIX]);
+            hasher.update(bincode::serialize(&values[i]).unwrap().as_slice());
+            nodes[values.len() + i].hash = *hasher.finalize().as_bytes();
+            nodes[values.len() + i].value = Some(values[i].clone());
+            hasher.reset();
+        }
+
+        // loop from `len(L) - 1` to 1
+        for i in (1..(values.len())).rev() {
+            hasher.update(&[NODE_HASH_PREFIX]);
+            hasher.update(&nodes[i * 2].hash[..]);
+            hasher.update(&nodes[i * 2 + 1].hash[..]);
+            nodes[i].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+        }
+
+        // nodes[0] is never used for anything.
+        MerkleTree {
+            root_hash: nodes[1].hash,
+            nodes,
+            height: log_2_floor(values.len() as u64) + 1,
+            num_leaves: values.len(),
+        }
+    }
+
+    /// `verify_proof` counterpart for trees built with `from_vec_domain_separated`.
+    pub fn verify_proof_domain_separated(
+        root_hash: [u8; 32],
+        index: u64,
+        proof: Vec<Node<T>>,
+    ) -> bool {
+        let mut mut_index = index + 2u64.pow(proof.len() as u32);
+        let mut v = proof[0].clone();
+        let mut hasher = blake3::Hasher::new();
+        for node in proof.iter().skip(1) {
+            hasher.update(&[NODE_HASH_PREFIX]);
+            if mut_index % 2 == 0 {
+                hasher.update(&v.hash[..]);
+                hasher.update(&node.hash[..]);
+            } else {
+                hasher.update(&node.hash[..]);
+                hasher.update(&v.hash[..]);
+            }
+            v.hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+            mut_index /= 2;
+        }
+
+        hasher.update(&[LEAF_HASH_PREFIX]);
+        hasher.update(
+            bincode::serialize(&proof[0].value.clone().unwrap())
+                .expect("Encoding failed")
+                .as_slice(),
+        );
+        let expected_hash = *hasher.finalize().as_bytes();
+        hasher.reset();
+
+        v.hash == root_hash && expected_hash == proof[0].hash
+    }
+
+    pub fn get_proof(&self, mut index: usize) -> Vec<Node<T>> {
+        let mut proof: Vec<Node<T>> = Vec::with_capacity(self.height as usize);
+        index += self.nodes.len() / 2;
+        proof.push(self.nodes[index].clone());
+        while index > 1 {
+            proof.push(self.nodes[index ^ 1].clone());
+            index /= 2;
+        }
+        proof
+    }
+
+    pub fn get_root(&self) -> [u8; 32] {
+        self.root_hash
+    }
+
+    pub fn get_number_of_leafs(&self) -> usize {
+        self.nodes.len() / 2
+    }
+
+    /// Append a new leaf without rebuilding the tree from scratch. Capacity
+    /// doubles (an O(capacity) remap of the existing leaves) whenever the tree
+    /// is full; otherwise only the new leaf's hash path up to the root is
+    /// recomputed, so this is O(log n) amortized per call. Existing leaves
+    /// keep their index: `get_proof`/`get_multi_proof` called with an index
+    /// obtained before a `push` still open the same value afterwards.
+    pub fn push(&mut self, value: T) {
+        let capacity = self.nodes.len() / 2;
+        if self.num_leaves == capacity {
+            self.double_capacity();
+        }
+
+        let capacity = self.nodes.len() / 2;
+        let mut index = capacity + self.num_leaves;
+        self.nodes[index].hash =
+            *blake3::hash(bincode::serialize(&value).unwrap().as_slice()).as_bytes();
+        self.nodes[index].value = Some(value);
+        self.num_leaves += 1;
+
+        let mut hasher = blake3::Hasher::new();
+        while index > 1 {
+            let parent = index / 2;
+            hasher.update(&self.nodes[parent * 2].hash[..]);
+            hasher.update(&self.nodes[parent * 2 + 1].hash[..]);
+            self.nodes[parent].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+            index = parent;
+        }
+        self.root_hash = self.nodes[1].hash;
+    }
+
+    /// Double the tree's capacity, carrying the existing leaves over to the
+    /// same logical offset in the (now twice as large) bottom layer. The
+    /// still-empty leaf slots opened up by the doubling are filled in by
+    /// later `push` calls.
+    fn double_capacity(&mut self) {
+        let old_capacity = self.nodes.len() / 2;
+        let new_capacity = if old_capacity == 0 { 1 } else { old_capacity * 2 };
+
+        let mut new_nodes: Vec<Node<T>> = vec![
+            Node {
+                value: None,
+                hash: [0u8; 32],
+            };
+            2 * new_capacity
+        ];
+        for i in 0..self.num_leaves {
+            new_nodes[new_capacity + i] = self.nodes[old_capacity + i].clone();
+        }
+        self.nodes = new_nodes;
+        self.height = log_2_floor(new_capacity as u64) + 1;
+        self.recompute_internal_hashes();
+    }
+
+    /// Recompute every internal-node hash (and `root_hash`) bottom-up from the
+    /// current leaf hashes. Used after `double_capacity` moves the leaves to a
+    /// new layout, since every internal node's pair of children changed.
+    fn recompute_internal_hashes(&mut self) {
+        let capacity = self.nodes.len() / 2;
+        let mut hasher = blake3::Hasher::new();
+        for i in (1..capacity).rev() {
+            hasher.update(&self.nodes[i * 2].hash[..]);
+            hasher.update(&self.nodes[i * 2 + 1].hash[..]);
+            self.nodes[i].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+        }
+        self.root_hash = self.nodes[1].hash;
+    }
+
+    pub fn verify_multi_proof(
+        root_hash: [u8; 32],
+        indices: &[usize],
+        proof: &[PartialAuthenticationPath<T>],
+    ) -> bool {
+        Self::verify_multi_proof_detailed(root_hash, indices, proof).is_ok()
+    }
+
+    /// Like `verify_multi_proof`, but on failure reports which opening (as a
+    /// position into `indices`/`proof`, not a leaf index) is the culprit, so a
+    /// caller like the FRI verifier can say which colinearity check it was that
+    /// failed rather than just "some proof was bad". Failures that aren't tied to
+    /// a single opening (malformed input, before any opening is checked) are
+    /// reported against index `0`.
+    pub fn verify_multi_proof_detailed(
+        root_hash: [u8; 32],
+        indices: &[usize],
+        proof: &[PartialAuthenticationPath<T>],
+    ) -> Result<(), usize> {
+        verify_multi_proof_detailed_with(root_hash, indices, proof, Self::verify_proof)
+    }
+
+    /// `indices` may contain the same index more than once -- e.g. the FRI query phase
+    /// can ask for the same tree position twice when an `a`/`b` index from one round
+    /// coincides with a `c` index carried over from the next. Duplicates are handled
+    /// deterministically: `output[i]` is always built the same way for the same
+    /// `indices` slice (later occurrences of a repeated index get pruned more
+    /// aggressively than the first, since by then its path overlaps positions already
+    /// scanned), so serializing the result is reproducible across calls. The returned
+    /// paths, checked together against `indices`, still verify via `verify_multi_proof`.
+    pub fn get_multi_proof(&self, indices: &[usize]) -> Vec<PartialAuthenticationPath<T>> {
+        let mut calculable_indices: HashSet<usize> = HashSet::new();
+        let mut output: Vec<PartialAuthenticationPath<T>> = Vec::with_capacity(indices.len());
+        for i in indices.iter() {
+            let new_branch: PartialAuthenticationPath<T> =
+                PartialAuthenticationPath(self.get_proof(*i).into_iter().map(Some).collect());
+            let mut index = self.nodes.len() / 2 + i;
+            calculable_indices.insert(index);
+            for _ in 1..new_branch.0.len() {
+                calculable_indices.insert(index ^ 1);
+                index /= 2;
+            }
+            output.push(new_branch);
+        }
+
+        let mut complete = false;
+        while !complete {
+            complete = true;
+            let mut keys: Vec<usize> = calculable_indices.iter().copied().map(|x| x / 2).collect();
+            // reverse sort, from big to small, This should be the fastest way to reverse sort.
+            // cf. https://stackoverflow.com/a/60916195/2574407
+            keys.sort_by_key(|w| Reverse(*w));
+            for key in keys.iter() {
+                if calculable_indices.contains(&(key * 2))
+                    && calculable_indices.contains(&(key * 2 + 1))
+                    && !calculable_indices.contains(key)
+                {
+                    calculable_indices.insert(*key);
+                    complete = false;
+                }
+            }
+        }
+
+        let mut scanned: HashSet<usize> = HashSet::new();
+        for (i, b) in indices.iter().zip(output.iter_mut()) {
+            let mut index: usize = self.nodes.len() / 2 + i;
+            scanned.insert(index);
+            for elem in b.0.iter_mut().skip(1) {
+                if calculable_indices.contains(&((index ^ 1) * 2))
+                    && calculable_indices.contains(&((index ^ 1) * 2 + 1))
+                    || (index ^ 1) as i64 - self.nodes.len() as i64 / 2 > 0 // TODO: Maybe > 1 here?
+                        && indices.contains(&((index ^ 1) - self.nodes.len() / 2))
+                    || scanned.contains(&(index ^ 1))
+                {
+                    *elem = None;
+                }
+                scanned.insert(index ^ 1);
+                index /= 2;
+            }
+        }
+
+        output
+    }
+
+    /// Re-derive a correctly pruned compressed proof for `subset_indices` (a subset of
+    /// `indices`, in any order) from a proof that's only guaranteed self-sufficient as
+    /// the *whole* batch of `indices` -- e.g. the result of `get_multi_proof(indices)`.
+    /// `get_multi_proof` prunes a node whenever it's reconstructable from some *other*
+    /// opened leaf in the batch; once the batch is narrowed down to `subset_indices`,
+    /// a node pruned only because of a leaf that didn't make the cut is no longer safe
+    /// to drop. This reassembles the tree implied by the full batch and re-prunes from
+    /// scratch against just `subset_indices`, producing exactly what
+    /// `get_multi_proof(subset_indices)` would have, without needing the original tree.
+    pub fn reprune_multi_proof(
+        indices: &[usize],
+        full_proof: &[PartialAuthenticationPath<T>],
+        subset_indices: &[usize],
+    ) -> Vec<PartialAuthenticationPath<T>> {
+        let half_tree_size = 2u64.pow(full_proof[0].0.len() as u32 - 1);
+
+        // Reassemble every node touched by the full batch, whether it was stored
+        // directly in `full_proof` or only reconstructable from a sibling elsewhere
+        // in the batch.
+        let mut partial_tree: HashMap<u64, Node<T>> = HashMap::new();
+        for (i, b) in indices.iter().zip(full_proof.iter()) {
+            let mut index = half_tree_size + *i as u64;
+            partial_tree.insert(index, b.0[0].clone().unwrap());
+            for elem in b.0.iter().skip(1) {
+                if let Some(node) = elem {
+                    partial_tree.insert(index ^ 1, node.clone());
+                }
+                index /= 2;
+            }
+        }
+
+        let mut complete = false;
+        let mut hasher = blake3::Hasher::new();
+        while !complete {
+            complete = true;
+            let mut keys: Vec<u64> = partial_tree.keys().copied().map(|x| x / 2).collect();
+            keys.sort_by_key(|w| Reverse(*w));
+            for key in keys {
+                if partial_tree.contains_key(&(key * 2))
+                    && partial_tree.contains_key(&(key * 2 + 1))
+                    && !partial_tree.contains_key(&key)
+                {
+                    hasher.update(&partial_tree[&(key * 2)].hash[..]);
+                    hasher.update(&partial_tree[&(key * 2 + 1)].hash[..]);
+                    partial_tree.insert(
+                        key,
+                        Node {
+                            value: None,
+                            hash: *hasher.finalize().as_bytes(),
+                        },
+                    );
+                    hasher.reset();
+                    complete = false;
+                }
+            }
+        }
+
+        // Now build a fresh, self-sufficient compressed proof for `subset_indices`,
+        // pruning exactly as `get_multi_proof` would if it had been asked for only
+        // these indices to begin with.
+        let path_len = full_proof[0].0.len();
+        let mut calculable_indices: HashSet<usize> = HashSet::new();
+        let mut output: Vec<PartialAuthenticationPath<T>> =
+            Vec::with_capacity(subset_indices.len());
+        for i in subset_indices.iter() {
+            let mut index = half_tree_size as usize + i;
+            let mut path: Vec<Option<Node<T>>> = Vec::with_capacity(path_len);
+            path.push(Some(partial_tree[&(index as u64)].clone()));
+            calculable_indices.insert(index);
+            for _ in 1..path_len {
+                path.push(Some(partial_tree[&((index ^ 1) as u64)].clone()));
+                calculable_indices.insert(index ^ 1);
+                index /= 2;
+            }
+            output.push(PartialAuthenticationPath(path));
+        }
+
+        let mut complete = false;
+        while !complete {
+            complete = true;
+            let mut keys: Vec<usize> = calculable_indices.iter().copied().map(|x| x / 2).collect();
+            keys.sort_by_key(|w| Reverse(*w));
+            for key in keys.iter() {
+                if calculable_indices.contains(&(key * 2))
+                    && calculable_indices.contains(&(key * 2 + 1))
+                    && !calculable_indices.contains(key)
+                {
+                    calculable_indices.insert(*key);
+                    complete = false;
+                }
+            }
+        }
+
+        let mut scanned: HashSet<usize> = HashSet::new();
+        for (i, b) in subset_indices.iter().zip(output.iter_mut()) {
+            let mut index: usize = half_tree_size as usize + i;
+            scanned.insert(index);
+            for elem in b.0.iter_mut().skip(1) {
+                if calculable_indices.contains(&((index ^ 1) * 2))
+                    && calculable_indices.contains(&((index ^ 1) * 2 + 1))
+                    || (index ^ 1) as i64 - half_tree_size as i64 > 0
+                        && subset_indices.contains(&((index ^ 1) - half_tree_size as usize))
+                    || scanned.contains(&(index ^ 1))
+                {
+                    *elem = None;
+                }
+                scanned.insert(index ^ 1);
+                index /= 2;
+            }
+        }
+
+        output
+    }
+
+    /// Build compressed authentication paths for several groups of indices against this
+    /// tree in one go, e.g. the `ab` indices of one FRI round and the `c` indices of the
+    /// next, which land on the same Merkle tree. Each group's proof is independently
+    /// self-sufficient (it verifies on its own via `verify_multi_proof`, without the
+    /// other groups' proofs present), which rules out pruning a node in one group's
+    /// proof just because some *other* group happens to also open it -- so this is
+    /// equivalent to, and no smaller than, calling `get_multi_proof` once per group.
+    pub fn get_multi_proof_batched(
+        &self,
+        index_groups: &[Vec<usize>],
+    ) -> Vec<Vec<PartialAuthenticationPath<T>>> {
+        index_groups
+            .iter()
+            .map(|indices| self.get_multi_proof(indices))
+            .collect()
+    }
+}
+
+impl<T: Clone + Debug + Hashable + PartialEq + Serialize> MerkleTree<T> {
+    /// Same as `from_vec`, but for leaves that implement `Hashable` directly: the
+    /// leaf hash preimage is `value.to_hash_preimage()` instead of
+    /// `bincode::serialize(value)`, avoiding bincode's length/format overhead for
+    /// leaves that are already fixed-size (e.g. `i128`, `u64`, `[u8; 32]`).
+    pub fn from_vec_raw(values: &[T]) -> Self {
+        // verify that length of input is power of 2
+        if values.len() & (values.len() - 1) != 0 {
+            panic!("Size of input for Merkle tree must be a power of 2");
+        }
+
+        let mut nodes: Vec<Node<T>> = vec![
+            Node {
+                value: None,
+                hash: [0u8; 32],
+            };
+            2 * values.len()
+        ];
+        for i in 0..values.len() {
+            nodes[values.len() + i].hash =
+                *blake3::hash(values[i].to_hash_preimage().as_slice()).as_bytes();
+            nodes[values.len() + i].value = Some(values[i].clone());
+        }
+
+        // loop from `len(L) - 1` to 1
+        let mut hasher = blake3::Hasher::new();
+        for i in (1..(values.len())).rev() {
+            hasher.update(&nodes[i * 2].hash[..]);
+            hasher.update(&nodes[i * 2 + 1].hash[..]);
+            nodes[i].hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+        }
+
+        // nodes[0] is never used for anything.
+        MerkleTree {
+            root_hash: nodes[1].hash,
+            nodes,
+            height: log_2_floor(values.len() as u64) + 1,
+            num_leaves: values.len(),
+        }
+    }
+
+    /// `verify_proof` counterpart for trees built with `from_vec_raw`: recomputes
+    /// the leaf hash from `value.to_hash_preimage()` instead of
+    /// `bincode::serialize(value)`. `verify_proof` itself can never accept a proof
+    /// from a `from_vec_raw` tree, since the two constructors disagree on the leaf
+    /// hash preimage.
+    pub fn verify_proof_raw(root_hash: [u8; 32], index: u64, proof: Vec<Node<T>>) -> bool {
+        let mut mut_index = index + 2u64.pow(proof.len() as u32);
+        let mut v = proof[0].clone();
+        let mut hasher = blake3::Hasher::new();
+        for node in proof.iter().skip(1) {
+            if mut_index % 2 == 0 {
+                hasher.update(&v.hash[..]);
+                hasher.update(&node.hash[..]);
+            } else {
+                hasher.update(&node.hash[..]);
+                hasher.update(&v.hash[..]);
+            }
+            v.hash = *hasher.finalize().as_bytes();
+            hasher.reset();
+            mut_index /= 2;
+        }
+        let expected_hash =
+            *blake3::hash(proof[0].value.clone().unwrap().to_hash_preimage().as_slice())
+                .as_bytes();
+        v.hash == root_hash && expected_hash == proof[0].hash
+    }
+
+    /// `verify_multi_proof` counterpart for trees built with `from_vec_raw`.
+    pub fn verify_multi_proof_raw(
+        root_hash: [u8; 32],
+        indices: &[usize],
+        proof: &[PartialAuthenticationPath<T>],
+    ) -> bool {
+        Self::verify_multi_proof_detailed_raw(root_hash, indices, proof).is_ok()
+    }
+
+    /// `verify_multi_proof_detailed` counterpart for trees built with
+    /// `from_vec_raw`.
+    pub fn verify_multi_proof_detailed_raw(
+        root_hash: [u8; 32],
+        indices: &[usize],
+        proof: &[PartialAuthenticationPath<T>],
+    ) -> Result<(), usize> {
+        verify_multi_proof_detailed_with(root_hash, indices, proof, Self::verify_proof_raw)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Clone + Serialize + Debug + PartialEq + Sync> MerkleTree<T> {
+    /// Same as `from_vec`, but hashes each layer (the leaves, then every level of
+    /// internal nodes) in parallel with rayon. Produces the exact same root as
+    /// `from_vec` for the same leaves; only worth it once there are enough leaves
+    /// to amortize the cost of spinning up parallel work, e.g. the 2^14-leaf FRI
+    /// trees this was written for.
+    pub fn from_vec_parallel(values: &[T]) -> Self {
+        // verify that length of input is power of 2
+        if values.len() & (values.len() - 1) != 0 {
+            panic!("Size of input for Merkle tree must be a power of 2");
+        }
+
+        let mut nodes: Vec<Node<T>> = vec![
+            Node {
+                value: None,
+                hash: [0u8; 32],
+            };
+            2 * values.len()
+        ];
+
+        let leaf_hashes: Vec<[u8; 32]> = values
+            .par_iter()
+            .map(|value| *blake3::hash(bincode::serialize(value).unwrap().as_slice()).as_bytes())
+            .collect();
+        for (i, hash) in leaf_hashes.into_iter().enumerate() {
+            nodes[values.len() + i].hash = hash;
+            nodes[values.len() + i].value = Some(values[i].clone());
+        }
+
+        // Combine one layer of children into their parents' hashes at a time,
+        // from the leaves up to the root, in parallel within each layer.
+        let mut layer_start = values.len();
+        let mut layer_len = values.len();
+        while layer_len > 1 {
+            let parent_hashes: Vec<[u8; 32]> = nodes[layer_start..layer_start + layer_len]
+                .par_chunks(2)
+                .map(|pair| {
+                    let mut hasher = blake3::Hasher::new();
+                    hasher.update(&pair[0].hash[..]);
+                    hasher.update(&pair[1].hash[..]);
+                    *hasher.finalize().as_bytes()
+                })
+                .collect();
+            let parent_start = layer_start / 2;
+            for (i, hash) in parent_hashes.into_iter().enumerate() {
+                nodes[parent_start + i].hash = hash;
+            }
+            layer_start = parent_start;
+            layer_len /= 2;
+        }
+
+        // nodes[0] is never used for anything.
+        MerkleTree {
+            root_hash: nodes[1].hash,
+            nodes,
+            height: log_2_floor(values.len() as u64) + 1,
+            num_leaves: values.len(),
+        }
+    }
+}
+
+/// Hash algorithm usable by `GenericMerkleTree`: produces a `Digest` in
+/// whatever representation that algorithm needs, e.g. 32 raw bytes for
+/// blake3/SHA-256, or a tuple of field elements for an algebraic hash like
+/// Rescue. `MerkleTree` itself stays hardcoded to `[u8; 32]`/blake3, since
+/// migrating its existing callers (FRI, STARK provers) to a generic digest
+/// is out of scope here; `GenericMerkleTree` is the parallel, digest-generic
+/// counterpart for callers that need an algebraic hash instead.
+pub trait MerkleHashAlgorithm {
+    type Digest: Clone + Debug + PartialEq;
+
+    fn hash_leaf(preimage: &[u8]) -> Self::Digest;
+    fn hash_pair(left: &Self::Digest, right: &Self::Digest) -> Self::Digest;
+}
+
+/// The hash algorithm `MerkleTree<T>` has always used: blake3 over the
+/// bincode-serialized leaf (or the bincode-serialized hash pair), producing
+/// a 32-byte digest.
+pub struct Blake3Algorithm;
+
+impl MerkleHashAlgorithm for Blake3Algorithm {
+    type Digest = [u8; 32];
+
+    fn hash_leaf(preimage: &[u8]) -> Self::Digest {
+        *blake3::hash(preimage).as_bytes()
+    }
+
+    fn hash_pair(left: &Self::Digest, right: &Self::Digest) -> Self::Digest {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(left);
+        hasher.update(right);
+        *hasher.finalize().as_bytes()
+    }
+}
+
+/// Like `Blake3Algorithm`, but routed through `utils::blake3_digest` instead of
+/// calling into `blake3::hash`/`blake3::Hasher` directly, so both places in the
+/// crate that hash raw bytes with blake3 go through the same function. Produces
+/// the exact same digests as `Blake3Algorithm` -- blake3's incremental `Hasher` is
+/// equivalent to hashing the concatenated input in one shot -- it's offered as a
+/// separate `MerkleHashAlgorithm` only for callers that specifically want to go
+/// through `blake3_digest`.
+pub struct Blake3Hasher;
+
+impl MerkleHashAlgorithm for Blake3Hasher {
+    type Digest = [u8; 32];
+
+    fn hash_leaf(preimage: &[u8]) -> Self::Digest {
+        crate::utils::blake3_digest(preimage)
+    }
+
+    fn hash_pair(left: &Self::Digest, right: &Self::Digest) -> Self::Digest {
+        let mut preimage = Vec::with_capacity(left.len() + right.len());
+        preimage.extend_from_slice(left);
+        preimage.extend_from_slice(right);
+        crate::utils::blake3_digest(&preimage)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericNode<T, D> {
+    pub value: Option<T>,
+    pub hash: D,
+}
+
+/// Same binary-heap array layout as `MerkleTree`, but the node hash is
+/// `H::Digest` instead of a hardcoded `[u8; 32]`. This lets an algebraic hash
+/// (e.g. Rescue over field elements) stand in for blake3/SHA-256, which is
+/// needed to keep a Merkle authentication path recursion-friendly inside
+/// another proof system. Covers single-leaf proofs only; it does not (yet)
+/// have `MerkleTree`'s compressed multi-proof machinery.
+pub struct GenericMerkleTree<T, H: MerkleHashAlgorithm> {
+    root_hash: H::Digest,
+    nodes: Vec<GenericNode<T, H::Digest>>,
+    height: u64,
+}
+
+impl<T: Clone + Serialize + Debug + PartialEq, H: MerkleHashAlgorithm> GenericMerkleTree<T, H> {
+    pub fn from_vec(values: &[T]) -> Self {
+        // verify that length of input is power of 2
+        if values.len() & (values.len() - 1) != 0 {
+            panic!("Size of input for Merkle tree must be a power of 2");
+        }
+
+        let mut nodes: Vec<GenericNode<T, H::Digest>> = (0..2 * values.len())
+            .map(|_| GenericNode {
+                value: None,
+                hash: H::hash_leaf(&[]),
+            })
+            .collect();
         for i in 0..values.len() {
             nodes[values.len() + i].hash =
-                *blake3::hash(bincode::serialize(&values[i]).unwrap().as_slice()).as_bytes();
+                H::hash_leaf(bincode::serialize(&values[i]).unwrap().as_slice());
             nodes[values.len() + i].value = Some(values[i].clone());
         }
 
         // loop from `len(L) - 1` to 1
-        let mut hasher = blake3::Hasher::new();
         for i in (1..(values.len())).rev() {
-            hasher.update(&nodes[i * 2].hash[..]);
-            hasher.update(&nodes[i * 2 + 1].hash[..]);
-            nodes[i].hash = *hasher.finalize().as_bytes();
-            hasher.reset();
+            nodes[i].hash = H::hash_pair(&nodes[i * 2].hash, &nodes[i * 2 + 1].hash);
         }
 
         // nodes[0] is never used for anything.
-        MerkleTree {
-            root_hash: nodes[1].hash,
+        GenericMerkleTree {
+            root_hash: nodes[1].hash.clone(),
             nodes,
             height: log_2_floor(values.len() as u64) + 1,
         }
     }
 
-    pub fn get_proof(&self, mut index: usize) -> Vec<Node<T>> {
-        let mut proof: Vec<Node<T>> = Vec::with_capacity(self.height as usize);
+    pub fn get_root(&self) -> H::Digest {
+        self.root_hash.clone()
+    }
+
+    pub fn get_proof(&self, mut index: usize) -> Vec<GenericNode<T, H::Digest>> {
+        let mut proof: Vec<GenericNode<T, H::Digest>> = Vec::with_capacity(self.height as usize);
         index += self.nodes.len() / 2;
         proof.push(self.nodes[index].clone());
         while index > 1 {
@@ -122,156 +1042,27 @@ impl<T: Clone + Serialize + Debug + PartialEq> MerkleTree<T> {
         proof
     }
 
-    pub fn get_root(&self) -> [u8; 32] {
-        self.root_hash
-    }
-
-    pub fn get_number_of_leafs(&self) -> usize {
-        self.nodes.len() / 2
-    }
-
-    pub fn verify_multi_proof(
-        root_hash: [u8; 32],
-        indices: &[usize],
-        proof: &[PartialAuthenticationPath<T>],
+    pub fn verify_proof(
+        root_hash: H::Digest,
+        index: u64,
+        proof: Vec<GenericNode<T, H::Digest>>,
     ) -> bool {
-        // compressed proofs can only be verified for all indices,
-        // meaning that all indices for the proof values must be known.
-        // This restriction is put in since the pruned parts of the
-        // multi proof are currently reassembled using the indices
-        // and some parts of the proof would be missing if all the proof
-        // elements were not represented in the indices argument.
-        if indices.len() != proof.len() {
-            return false;
-        }
-
-        let mut partial_tree: HashMap<u64, Node<T>> = HashMap::new();
-        let mut proof_clone: Vec<PartialAuthenticationPath<T>> = proof.to_owned();
-        let half_tree_size = 2u64.pow(proof_clone[0].0.len() as u32 - 1);
-        for (i, b) in indices.iter().zip(proof_clone.iter_mut()) {
-            let mut index = half_tree_size + *i as u64;
-            partial_tree.insert(index, b.0[0].clone().unwrap());
-            for elem in b.0.iter_mut().skip(1) {
-                if let Some(i) = elem.clone() {
-                    partial_tree.insert(index ^ 1, i);
-                }
-                index /= 2;
-            }
-        }
-
-        let mut complete = false;
-        let mut hasher = blake3::Hasher::new();
-        while !complete {
-            complete = true;
-            //let mut keys: Vec<usize> = partial_tree.iter().copied().map(|x| x / 2).collect();
-            let mut keys: Vec<u64> = partial_tree.keys().copied().map(|x| x / 2).collect();
-            keys.sort_by_key(|w| Reverse(*w));
-            for key in keys {
-                if partial_tree.contains_key(&(key * 2))
-                    && partial_tree.contains_key(&(key * 2 + 1))
-                    && !partial_tree.contains_key(&key)
-                {
-                    hasher.update(&partial_tree[&(key * 2)].hash[..]);
-                    hasher.update(&partial_tree[&(key * 2 + 1)].hash[..]);
-                    partial_tree.insert(
-                        key,
-                        Node {
-                            value: None,
-                            hash: *hasher.finalize().as_bytes(),
-                        },
-                    );
-                    hasher.reset();
-                    complete = false;
-                }
-            }
-        }
-
-        for (i, b) in indices.iter().zip(proof_clone.iter_mut()) {
-            let mut index = half_tree_size + *i as u64;
-            for elem in b.0.iter_mut().skip(1) {
-                if *elem == None {
-                    // If the Merkle tree/proof is manipulated, the value partial_tree[&(index ^ 1)]
-                    // is not guaranteed to exist. So have to  check
-                    // whether it exists and return false if it does not
-                    if !partial_tree.contains_key(&(index ^ 1)) {
-                        return false;
-                    }
-
-                    *elem = Some(partial_tree[&(index ^ 1)].clone());
-                }
-                partial_tree.insert(index ^ 1, elem.clone().unwrap());
-                index /= 2;
-            }
-        }
-
-        for i in 0..indices.len() {
-            let proof_clone_unwrapped: Vec<Node<T>> = proof_clone[i]
-                .0
-                .clone()
-                .into_iter()
-                .map(|x| x.unwrap())
-                .collect();
-            // println!("input_proof = {:?}", proof[i]);
-            // println!("proof_clone_unwrapped = {:?}", proof_clone_unwrapped);
-            if !Self::verify_proof(root_hash, indices[i] as u64, proof_clone_unwrapped) {
-                return false;
-            }
-        }
-        true
-    }
-
-    pub fn get_multi_proof(&self, indices: &[usize]) -> Vec<PartialAuthenticationPath<T>> {
-        let mut calculable_indices: HashSet<usize> = HashSet::new();
-        let mut output: Vec<PartialAuthenticationPath<T>> = Vec::with_capacity(indices.len());
-        for i in indices.iter() {
-            let new_branch: PartialAuthenticationPath<T> =
-                PartialAuthenticationPath(self.get_proof(*i).into_iter().map(Some).collect());
-            let mut index = self.nodes.len() / 2 + i;
-            calculable_indices.insert(index);
-            for _ in 1..new_branch.0.len() {
-                calculable_indices.insert(index ^ 1);
-                index /= 2;
-            }
-            output.push(new_branch);
-        }
-
-        let mut complete = false;
-        while !complete {
-            complete = true;
-            let mut keys: Vec<usize> = calculable_indices.iter().copied().map(|x| x / 2).collect();
-            // reverse sort, from big to small, This should be the fastest way to reverse sort.
-            // cf. https://stackoverflow.com/a/60916195/2574407
-            keys.sort_by_key(|w| Reverse(*w));
-            for key in keys.iter() {
-                if calculable_indices.contains(&(key * 2))
-                    && calculable_indices.contains(&(key * 2 + 1))
-                    && !calculable_indices.contains(key)
-                {
-                    calculable_indices.insert(*key);
-                    complete = false;
-                }
-            }
-        }
-
-        let mut scanned: HashSet<usize> = HashSet::new();
-        for (i, b) in indices.iter().zip(output.iter_mut()) {
-            let mut index: usize = self.nodes.len() / 2 + i;
-            scanned.insert(index);
-            for elem in b.0.iter_mut().skip(1) {
-                if calculable_indices.contains(&((index ^ 1) * 2))
-                    && calculable_indices.contains(&((index ^ 1) * 2 + 1))
-                    || (index ^ 1) as i64 - self.nodes.len() as i64 / 2 > 0 // TODO: Maybe > 1 here?
-                        && indices.contains(&((index ^ 1) - self.nodes.len() / 2))
-                    || scanned.contains(&(index ^ 1))
-                {
-                    *elem = None;
-                }
-                scanned.insert(index ^ 1);
-                index /= 2;
-            }
+        let mut mut_index = index + 2u64.pow(proof.len() as u32);
+        let mut v = proof[0].clone();
+        for node in proof.iter().skip(1) {
+            v.hash = if mut_index % 2 == 0 {
+                H::hash_pair(&v.hash, &node.hash)
+            } else {
+                H::hash_pair(&node.hash, &v.hash)
+            };
+            mut_index /= 2;
         }
-
-        output
+        let expected_hash = H::hash_leaf(
+            bincode::serialize(&proof[0].value.clone().unwrap())
+                .expect("Encoding failed")
+                .as_slice(),
+        );
+        v.hash == root_hash && expected_hash == proof[0].hash
     }
 }
 
@@ -281,6 +1072,7 @@ mod merkle_tree_test {
     use crate::shared_math::prime_field_element::{PrimeField, PrimeFieldElement};
     use crate::utils::{decode_hex, generate_random_numbers};
     use itertools::Itertools;
+    use std::convert::TryInto;
 
     #[test]
     fn merkle_tree_test_32() {
@@ -366,6 +1158,311 @@ mod merkle_tree_test {
         }
     }
 
+    #[test]
+    fn merkle_tree_from_rows_test() {
+        // Commit to a 3-column, 8-row trace and open row 5 to recover all three values.
+        let rows: Vec<Vec<i128>> = (0..8).map(|i| vec![i, i * 2, i * 3]).collect();
+        let trace_mt: MerkleTree<Vec<i128>> = MerkleTree::from_rows(&rows);
+        let proof = trace_mt.get_multi_proof(&[5]);
+        assert!(MerkleTree::verify_multi_proof(
+            trace_mt.get_root(),
+            &[5],
+            &proof
+        ));
+        assert_eq!(vec![5, 10, 15], proof[0].get_value());
+        assert_eq!(&vec![5, 10, 15], proof[0].get_value_ref());
+    }
+
+    #[test]
+    fn merkle_tree_from_vec_raw_test() {
+        let leaves: Vec<i128> = (0..8).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec_raw(&leaves);
+        let proof = mt.get_multi_proof(&[3]);
+        assert!(MerkleTree::verify_multi_proof_raw(mt.get_root(), &[3], &proof));
+        assert_eq!(3, proof[0].get_value());
+
+        // `from_vec_raw` hashes leaves differently from `from_vec`, so the two
+        // constructors must not produce the same root for the same leaves.
+        let mt_bincode: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        assert_ne!(mt.get_root(), mt_bincode.get_root());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn merkle_tree_from_vec_parallel_matches_sequential_test() {
+        let field = PrimeField::new(1009);
+        let elements: Vec<PrimeFieldElement> = generate_random_numbers(1024, 1000)
+            .iter()
+            .map(|x| PrimeFieldElement::new(*x, &field))
+            .collect();
+        let sequential: MerkleTree<PrimeFieldElement> = MerkleTree::from_vec(&elements);
+        let parallel: MerkleTree<PrimeFieldElement> = MerkleTree::from_vec_parallel(&elements);
+        assert_eq!(sequential.get_root(), parallel.get_root());
+    }
+
+    #[test]
+    fn merkle_tree_from_iter_matches_from_vec_test() {
+        let leaves: Vec<i128> = (0..16).collect();
+        let mt_from_iter: MerkleTree<i128> = MerkleTree::from_iter(leaves.clone().into_iter(), 16);
+        let mt_from_vec: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        assert_eq!(mt_from_vec.get_root(), mt_from_iter.get_root());
+    }
+
+    #[test]
+    fn merkle_tree_domain_separated_proof_round_trip_test() {
+        let leaves: Vec<i128> = (0..8).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec_domain_separated(&leaves);
+        let proof = mt.get_proof(3);
+        assert!(MerkleTree::verify_proof_domain_separated(
+            mt.get_root(),
+            3,
+            proof.clone()
+        ));
+
+        // Opt-in: a domain-separated tree's root/proofs don't verify against the
+        // plain (non-separated) verifier, and vice versa.
+        assert!(!MerkleTree::verify_proof(mt.get_root(), 3, proof));
+        let mt_plain: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        assert_ne!(mt.get_root(), mt_plain.get_root());
+    }
+
+    #[test]
+    fn merkle_tree_push_matches_from_vec_at_power_of_two_sizes_test() {
+        // `from_vec` only accepts power-of-two leaf counts, so a `push`-built
+        // tree can only be compared against it when its leaf count is a power
+        // of two, e.g. once it holds 8 and then 16 leaves.
+        let values: Vec<i128> = (0..16).collect();
+        let mut pushed: MerkleTree<i128> = MerkleTree::from_vec(&[values[0]]);
+        for value in values.iter().skip(1) {
+            pushed.push(*value);
+            let leaves_so_far = pushed.to_vec().len();
+            if leaves_so_far.is_power_of_two() {
+                let from_scratch: MerkleTree<i128> =
+                    MerkleTree::from_vec(&values[..leaves_so_far]);
+                assert_eq!(from_scratch.get_root(), pushed.get_root());
+            }
+        }
+        assert_eq!(values, pushed.to_vec());
+    }
+
+    #[test]
+    fn merkle_tree_push_keeps_existing_indices_stable_test() {
+        // Grow a one-leaf tree to ten leaves one push at a time (crossing the
+        // 8 -> 16 capacity doubling boundary along the way) and verify that
+        // every leaf, including those pushed before the doubling, still opens
+        // at its original index afterwards.
+        let mut mt: MerkleTree<i128> = MerkleTree::from_vec(&[0i128]);
+        for value in 1..10i128 {
+            mt.push(value);
+        }
+        assert_eq!(10, mt.to_vec().len());
+
+        for index in 0..10usize {
+            let proof = mt.get_proof(index);
+            assert!(MerkleTree::verify_proof(mt.get_root(), index as u64, proof.clone()));
+            assert_eq!(Some(index as i128), proof[0].value);
+        }
+    }
+
+    #[test]
+    fn generic_merkle_tree_with_two_field_element_digest_stub_test() {
+        // Stand-in for an algebraic hash (e.g. Rescue) whose digest is a pair
+        // of field elements rather than 32 bytes. The combining rule here is
+        // not cryptographically meaningful; it only has to be deterministic
+        // and exercise the `MerkleHashAlgorithm` plumbing.
+        type FieldElementDigest = [u64; 2];
+
+        struct StubFieldHashAlgorithm;
+        impl MerkleHashAlgorithm for StubFieldHashAlgorithm {
+            type Digest = FieldElementDigest;
+
+            fn hash_leaf(preimage: &[u8]) -> Self::Digest {
+                let sum: u64 = preimage.iter().map(|&b| b as u64).sum();
+                [sum, sum.wrapping_mul(31).wrapping_add(preimage.len() as u64)]
+            }
+
+            fn hash_pair(left: &Self::Digest, right: &Self::Digest) -> Self::Digest {
+                [
+                    left[0].wrapping_add(right[0]),
+                    left[1].wrapping_mul(31).wrapping_add(right[1]),
+                ]
+            }
+        }
+
+        let leaves: Vec<i128> = (0..8).collect();
+        let mt: GenericMerkleTree<i128, StubFieldHashAlgorithm> = GenericMerkleTree::from_vec(&leaves);
+        let proof = mt.get_proof(3);
+        assert!(GenericMerkleTree::<i128, StubFieldHashAlgorithm>::verify_proof(
+            mt.get_root(),
+            3,
+            proof.clone()
+        ));
+        assert_eq!(Some(3), proof[0].value);
+
+        // Tampering with the leaf value must break verification.
+        let mut bad_proof = proof;
+        bad_proof[0].value = Some(4);
+        assert!(!GenericMerkleTree::<i128, StubFieldHashAlgorithm>::verify_proof(
+            mt.get_root(),
+            3,
+            bad_proof
+        ));
+    }
+
+    #[test]
+    fn blake3_hasher_produces_deterministic_roots_test() {
+        let leaves: Vec<i128> = (0..8).collect();
+        let first: GenericMerkleTree<i128, Blake3Hasher> = GenericMerkleTree::from_vec(&leaves);
+        let second: GenericMerkleTree<i128, Blake3Hasher> = GenericMerkleTree::from_vec(&leaves);
+        assert_eq!(first.get_root(), second.get_root());
+
+        // `Blake3Hasher` and `Blake3Algorithm` only differ in how they call into
+        // blake3, not in what they compute -- they must agree bit for bit.
+        let via_algorithm: GenericMerkleTree<i128, Blake3Algorithm> =
+            GenericMerkleTree::from_vec(&leaves);
+        assert_eq!(via_algorithm.get_root(), first.get_root());
+    }
+
+    #[test]
+    fn blake3_hasher_pins_known_root_test() {
+        let leaves: Vec<i128> = (0..8).collect();
+        let mt: GenericMerkleTree<i128, Blake3Hasher> = GenericMerkleTree::from_vec(&leaves);
+        let expected: [u8; 32] =
+            decode_hex("ffa0e87015aa95bad309a2be91aeddf75718bf6080c32459ffc78478eaf85910")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(expected, mt.get_root());
+    }
+
+    #[test]
+    fn leaf_and_node_hash_of_same_bytes_differ_under_domain_separation_test() {
+        let some_hash = [7u8; 32];
+        let mut leaf_hasher = blake3::Hasher::new();
+        leaf_hasher.update(&[LEAF_HASH_PREFIX]);
+        leaf_hasher.update(&some_hash);
+        let leaf_hash = *leaf_hasher.finalize().as_bytes();
+
+        let mut node_hasher = blake3::Hasher::new();
+        node_hasher.update(&[NODE_HASH_PREFIX]);
+        node_hasher.update(&some_hash);
+        let node_hash = *node_hasher.finalize().as_bytes();
+
+        assert_ne!(leaf_hash, node_hash);
+    }
+
+    #[test]
+    #[cfg(feature = "serialization-serde")]
+    fn merkle_tree_serialization_round_trip_test() {
+        let field = PrimeField::new(1009);
+        let elements: Vec<PrimeFieldElement> = generate_random_numbers(8, 1000)
+            .iter()
+            .map(|x| PrimeFieldElement::new(*x, &field))
+            .collect();
+        let mt: MerkleTree<PrimeFieldElement> = MerkleTree::from_vec(&elements);
+        let serialized = bincode::serialize(&mt).expect("serializing MerkleTree failed");
+        let deserialized: MerkleTree<PrimeFieldElement> =
+            bincode::deserialize(&serialized).expect("deserializing MerkleTree failed");
+        assert_eq!(mt.get_root(), deserialized.get_root());
+    }
+
+    #[test]
+    fn get_multi_proof_batched_test() {
+        let field = PrimeField::new(1009);
+        let elements: Vec<PrimeFieldElement> = generate_random_numbers(2usize.pow(14), 1000)
+            .iter()
+            .map(|x| PrimeFieldElement::new(*x, &field))
+            .collect();
+        let mt = MerkleTree::from_vec(&elements);
+
+        let group_a: Vec<usize> = (0..40).collect();
+        let group_b: Vec<usize> = (20..60).collect();
+
+        let naive_size = bincode::serialize(&mt.get_multi_proof(&group_a))
+            .unwrap()
+            .len()
+            + bincode::serialize(&mt.get_multi_proof(&group_b))
+                .unwrap()
+                .len();
+
+        let batched = mt.get_multi_proof_batched(&[group_a.clone(), group_b.clone()]);
+        let batched_size: usize = batched.iter().map(|p| bincode::serialize(p).unwrap().len()).sum();
+
+        assert!(batched_size <= naive_size);
+        assert!(MerkleTree::verify_multi_proof(
+            mt.get_root(),
+            &group_a,
+            &batched[0]
+        ));
+        assert!(MerkleTree::verify_multi_proof(
+            mt.get_root(),
+            &group_b,
+            &batched[1]
+        ));
+    }
+
+    #[test]
+    fn verify_multi_proof_rejects_out_of_range_index_test() {
+        let leaves: Vec<i128> = (0..4).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        let proof = mt.get_multi_proof(&[1]);
+        assert!(!MerkleTree::verify_multi_proof(mt.get_root(), &[10], &proof));
+    }
+
+    #[test]
+    fn verify_multi_proof_rejects_contradictory_duplicate_index_test() {
+        let leaves: Vec<i128> = (0..4).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        let proof = mt.get_multi_proof(&[0, 1]);
+
+        // Claim index 0 twice, but pair the second claim with the proof that
+        // actually opens leaf 1 -- the two "openings" of index 0 disagree.
+        assert!(!MerkleTree::verify_multi_proof(
+            mt.get_root(),
+            &[0, 0],
+            &[proof[0].clone(), proof[1].clone()]
+        ));
+    }
+
+    #[test]
+    fn get_multi_proof_is_deterministic_and_verifies_for_repeated_indices_test() {
+        let leaves: Vec<i128> = (0..8).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        let indices = [3, 3, 5];
+
+        let first = mt.get_multi_proof(&indices);
+        let second = mt.get_multi_proof(&indices);
+        let first_serialized = bincode::serialize(&first).unwrap();
+        let second_serialized = bincode::serialize(&second).unwrap();
+        assert_eq!(first_serialized, second_serialized);
+
+        // Regression pin: catches any future change to `get_multi_proof` that alters
+        // how aggressively repeated indices get pruned, even if it doesn't break
+        // verification.
+        assert_eq!(355, first_serialized.len());
+
+        assert!(MerkleTree::verify_multi_proof(mt.get_root(), &indices, &first));
+    }
+
+    #[test]
+    fn verify_multi_proof_detailed_reports_position_of_tampered_opening_test() {
+        let leaves: Vec<i128> = (0..4).collect();
+        let mt: MerkleTree<i128> = MerkleTree::from_vec(&leaves);
+        let mut proof = mt.get_multi_proof(&[0, 1]);
+
+        // Both openings are valid on their own; only the second (index 1 into
+        // `indices`/`proof`) is tampered, so that's the position that should
+        // be reported, not 0.
+        let mut tampered_leaf = proof[1].0[0].clone().unwrap();
+        tampered_leaf.value = Some(leaves[1] + 1);
+        proof[1].0[0] = Some(tampered_leaf);
+
+        assert_eq!(
+            Err(1),
+            MerkleTree::verify_multi_proof_detailed(mt.get_root(), &[0, 1], &proof)
+        );
+    }
+
     #[test]
     fn merkle_tree_test_simple() {
         let single_mt_one: MerkleTree<i128> = MerkleTree::from_vec(&[1i128]);
@@ -503,4 +1600,28 @@ mod merkle_tree_test {
             &compressed_proof
         ));
     }
+
+    #[test]
+    fn from_vec_of_empty_input_has_the_empty_hash_as_root_test() {
+        let empty_tree: MerkleTree<i128> = MerkleTree::from_vec(&[]);
+        assert_eq!(*blake3::hash(&[]).as_bytes(), empty_tree.get_root());
+        assert_eq!(0, empty_tree.get_number_of_leafs());
+    }
+
+    #[test]
+    fn from_vec_of_single_leaf_has_that_leafs_hash_as_root_test() {
+        let leaf = 42i128;
+        let single_leaf_tree: MerkleTree<i128> = MerkleTree::from_vec(&[leaf]);
+        let expected_root =
+            *blake3::hash(bincode::serialize(&leaf).unwrap().as_slice()).as_bytes();
+        assert_eq!(expected_root, single_leaf_tree.get_root());
+    }
+
+    #[test]
+    fn get_multi_proof_of_no_indices_is_trivially_empty_and_verifies_test() {
+        let tree: MerkleTree<i128> = MerkleTree::from_vec(&[1, 2, 3, 4]);
+        let proof = tree.get_multi_proof(&[]);
+        assert!(proof.is_empty());
+        assert!(MerkleTree::verify_multi_proof(tree.get_root(), &[], &proof));
+    }
 }
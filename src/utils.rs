@@ -1,4 +1,5 @@
-use rand::RngCore;
+use rand::{RngCore, SeedableRng};
+use rand_pcg::Pcg64;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::num::ParseIntError;
@@ -842,34 +843,100 @@ pub fn generate_random_numbers(size: usize, modulus: i128) -> Vec<i128> {
     values
 }
 
+// Same as `generate_random_numbers`, but seeded so that a failing test can be
+// reproduced by re-running with the same seed instead of relying on OS entropy.
+pub fn generate_random_numbers_seeded(size: usize, modulus: i128, seed: u64) -> Vec<i128> {
+    let mut prng = Pcg64::seed_from_u64(seed);
+
+    let values: Vec<i128> = (0..size)
+        .map(|_| (((prng.next_u64() as i128) << 63) | (prng.next_u64() as i128) >> 1) % modulus)
+        .collect();
+    values
+}
+
 pub fn blake3_digest(input: &[u8]) -> [u8; 32] {
     *blake3::hash(input).as_bytes()
 }
 
-pub fn get_n_hash_rounds(input: &[u8], n: u32) -> Vec<[u8; 32]> {
-    let mut output: Vec<[u8; 32]> = vec![];
-    for i in 0..n {
-        let mut input_clone = input.to_vec();
+// An unbounded stream of hashes of `seed` appended with an incrementing u32
+// counter, i.e. the same construction `get_n_hash_rounds` used to build up
+// front, but produced lazily so callers that only need a handful of rounds
+// don't pay for the rest.
+pub struct HashRoundsIter {
+    seed: Vec<u8>,
+    next_round: u32,
+}
+
+impl Iterator for HashRoundsIter {
+    type Item = [u8; 32];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.next_round;
+        self.next_round = self.next_round.checked_add(1)?;
+
+        let mut input = self.seed.clone();
 
         // Convert i: usize into a byte array of length 4
         let ip: *const u32 = &i;
         let bp: *const u8 = ip as *const _;
         let bs: &[u8] = unsafe { slice::from_raw_parts(bp, mem::size_of::<u32>()) };
 
-        input_clone.append(&mut bs.to_vec());
-        let hash = *blake3::hash(input_clone.as_slice()).as_bytes();
-        output.push(hash);
+        input.extend_from_slice(bs);
+        Some(*blake3::hash(input.as_slice()).as_bytes())
+    }
+}
+
+pub fn hash_rounds_iter(seed: &[u8]) -> HashRoundsIter {
+    HashRoundsIter {
+        seed: seed.to_vec(),
+        next_round: 0,
     }
-    output
 }
 
-// TODO: Not sure I trust the uniformity of this!!
+pub fn get_n_hash_rounds(input: &[u8], n: u32) -> Vec<[u8; 32]> {
+    hash_rounds_iter(input).take(n as usize).collect()
+}
+
+// Number of bytes drawn per rejection-sampling attempt. 16 bytes (a u128) keeps
+// the bias from the final modulo reduction far below any measurable threshold,
+// even for `length` close to 2^64, while still leaving room to draw several
+// independent attempts out of a 32-byte hash.
+const INDEX_SAMPLE_WINDOW: usize = 16;
+
+// Maps `buf` to an index in `0..length`. If `length` is a power of two, every
+// bit of the sampled value contributes evenly, so a plain modulo is unbiased.
+// Otherwise a plain modulo would favor the low indices, so this draws
+// successive `INDEX_SAMPLE_WINDOW`-byte chunks of `buf` and rejects any chunk
+// that falls in the excess range above the largest multiple of `length` a
+// u128 can hold, per standard rejection sampling. If every chunk is rejected
+// (astronomically unlikely), it falls back to the modulo of the last chunk
+// rather than panicking.
 pub fn get_index_from_bytes(buf: &[u8], length: usize) -> usize {
-    let mut result = 0usize;
-    for elem in buf.iter() {
-        result = (result << 8 ^ *elem as usize) % length;
+    assert!(length > 0, "length must be positive");
+
+    let chunk_size = INDEX_SAMPLE_WINDOW.min(buf.len()).max(1);
+    let threshold = if length.is_power_of_two() {
+        None
+    } else {
+        let length = length as u128;
+        Some(u128::MAX - (u128::MAX % length))
+    };
+
+    let chunk_value = |chunk: &[u8]| -> u128 {
+        chunk.iter().fold(0u128, |acc, &byte| (acc << 8) | byte as u128)
+    };
+
+    for chunk in buf.chunks(chunk_size) {
+        let value = chunk_value(chunk);
+        if let Some(threshold) = threshold {
+            if value >= threshold {
+                continue;
+            }
+        }
+        return (value % length as u128) as usize;
     }
-    result
+
+    (chunk_value(&buf[buf.len() - chunk_size..]) % length as u128) as usize
 }
 
 // Used in Merkle Tree tests and in STARK tests
@@ -937,4 +1004,47 @@ mod test_utils {
         let v = vec![10, 20, 30, 40, 50];
         assert!(has_unique_elements(v));
     }
+
+    #[test]
+    fn hash_rounds_iter_matches_get_n_hash_rounds_test() {
+        let seed = vec![5, 6, 7];
+        let n = 8;
+        let expected = get_n_hash_rounds(&seed, n);
+        let actual: Vec<[u8; 32]> = hash_rounds_iter(&seed).take(n as usize).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn get_index_from_bytes_non_power_of_two_is_roughly_uniform_test() {
+        let upper = 777usize; // not a power of two
+        let buckets = 10;
+        let mut counts = vec![0usize; buckets];
+        let samples = 100_000;
+        let mut prng = Pcg64::seed_from_u64(7);
+        let mut buf = [0u8; 32];
+        for _ in 0..samples {
+            for byte in buf.iter_mut() {
+                *byte = (prng.next_u64() & 0xff) as u8;
+            }
+            let index = get_index_from_bytes(&buf, upper);
+            assert!(index < upper);
+            counts[index * buckets / upper] += 1;
+        }
+
+        let expected = samples / buckets;
+        for count in counts {
+            let deviation = (count as f64 - expected as f64).abs() / expected as f64;
+            assert!(deviation < 0.05, "bucket deviated by {}", deviation);
+        }
+    }
+
+    #[test]
+    fn generate_random_numbers_seeded_is_deterministic_test() {
+        let a = generate_random_numbers_seeded(20, 7919, 42);
+        let b = generate_random_numbers_seeded(20, 7919, 42);
+        assert_eq!(a, b);
+
+        let c = generate_random_numbers_seeded(20, 7919, 43);
+        assert_ne!(a, c);
+    }
 }